@@ -0,0 +1,67 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "Failed to read input: {}", e),
+            Error::Parse(e) => write!(f, "Failed to parse input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+// Describes a piece of input that didn't match what the parser expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize, // 1-based line number, 0 until the error is placed with at_line
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, expected: &str) -> Self {
+        ParseError {
+            line: 0,
+            text: String::from(text),
+            expected: String::from(expected),
+        }
+    }
+
+    // Records which line (0-based index) the error came from
+    pub fn at_line(mut self, index: usize) -> Self {
+        self.line = index + 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: expected {}, found '{}'",
+            self.line, self.expected, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
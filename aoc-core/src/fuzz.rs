@@ -0,0 +1,175 @@
+use crate::{input, Error, Generate, Rng, Solution};
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+// Fragments that are likely to upset a parser when spliced into otherwise valid input
+const TOKENS: &[&str] = &[
+    "\n",
+    " ",
+    "0",
+    "-",
+    ",",
+    "\r\n",
+    "\u{feff}",
+    "é",
+    "4294967295",
+    "99999999999999999999",
+    "$ cd ..",
+    "$ cd /",
+    "$ ls",
+    "dir a",
+    "[A] ",
+    "move 1 from 1 to 1",
+    "move 99999 from 1 to 2",
+];
+
+// An input that made a day panic, or that it rejected without saying which line was wrong
+#[derive(Debug)]
+pub struct Crash {
+    pub seed: u64,
+    pub input: Vec<u8>,
+    pub message: String,
+}
+
+// Applies a handful of random byte and line level edits to `base`
+pub fn mutate(rng: &mut Rng, base: &[u8]) -> Vec<u8> {
+    let mut bytes = base.to_vec();
+
+    for _ in 0..rng.between(1, 8) {
+        let at = rng.index(bytes.len() + 1);
+
+        match rng.below(6) {
+            0 if at < bytes.len() => bytes[at] = rng.below(256) as u8,
+            1 => bytes.insert(at, rng.below(256) as u8),
+            2 => {
+                let end = (at + rng.index(8)).min(bytes.len());
+                bytes.drain(at..end);
+            }
+            3 => {
+                let lines: Vec<&[u8]> = base.split(|&b| b == b'\n').collect();
+                let mut line = rng.pick(&lines).to_vec();
+                line.push(b'\n');
+                bytes.splice(at..at, line);
+            }
+            4 => {
+                let token = rng.pick(TOKENS).as_bytes();
+                bytes.splice(at..at, token.iter().copied());
+            }
+            _ => bytes.truncate(at),
+        }
+    }
+
+    bytes
+}
+
+// Feeds arbitrary bytes to the day's parser and, when they parse, to both parts. Rejecting the
+// input is fine as long as the error points at one of its lines; otherwise, or if anything
+// panicked, returns what went wrong.
+pub fn try_input<S: Solution>(bytes: &[u8]) -> Result<(), String> {
+    let contents = input::normalize(&String::from_utf8_lossy(bytes), S::TRIM);
+    let lines = contents.lines().count();
+
+    panic::catch_unwind(AssertUnwindSafe(|| match S::parse(&contents) {
+        Ok(parsed) => {
+            S::part1(&parsed);
+            S::part2(&parsed);
+            Ok(())
+        }
+        // A missing line is reported as the one just past the end
+        Err(Error::Parse(e)) if (1..=lines + 1).contains(&e.line) => Ok(()),
+        Err(e) => Err(format!("rejected without a line number: {}", e)),
+    }))
+    .map_err(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            String::from(*message)
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("unknown panic")
+        };
+
+        format!("panicked: {}", message)
+    })?
+}
+
+// Mutates the corpus entries, or freshly generated inputs, once per seed and returns the first
+// input that crashes the day
+pub fn fuzz<S: Solution + Generate>(seeds: Range<u64>, corpus: &[&str]) -> Option<Crash> {
+    for seed in seeds {
+        let mut rng = Rng::new(seed);
+
+        let base = if corpus.is_empty() || rng.chance(1, 2) {
+            let size = rng.between(1, 20) as usize;
+            S::generate(&mut rng, size)
+        } else {
+            String::from(*rng.pick(corpus))
+        };
+
+        let input = mutate(&mut rng, base.as_bytes());
+
+        if let Err(message) = try_input::<S>(&input) {
+            return Some(Crash {
+                seed,
+                input,
+                message,
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    // Sums one number per line, placing its parse errors only when `PLACED` is set, and panics on
+    // the line 'boom'
+    struct Numbers<const PLACED: bool>;
+
+    impl<const PLACED: bool> Solution for Numbers<PLACED> {
+        const DAY: u32 = 0;
+
+        type Input = u64;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(contents: &str) -> Result<Self::Input, Error> {
+            let mut sum = 0;
+
+            for (i, line) in contents.lines().enumerate() {
+                if line == "boom" {
+                    panic!("boom");
+                }
+
+                let error = ParseError::new(line, "a number");
+                sum +=
+                    line.parse::<u64>()
+                        .map_err(|_| if PLACED { error.at_line(i) } else { error })?;
+            }
+
+            Ok(sum)
+        }
+
+        fn part1(sum: &Self::Input) -> Self::Answer1 {
+            *sum
+        }
+
+        fn part2(sum: &Self::Input) -> Self::Answer2 {
+            *sum
+        }
+    }
+
+    #[test]
+    fn accepts_only_errors_that_name_a_line() {
+        assert_eq!(try_input::<Numbers<true>>(b"1\n2\n"), Ok(()));
+        assert_eq!(try_input::<Numbers<true>>(b"1\nx\n"), Ok(()));
+
+        let rejected = try_input::<Numbers<false>>(b"1\nx\n").unwrap_err();
+        assert!(rejected.starts_with("rejected without a line number"));
+
+        let panicked = try_input::<Numbers<true>>(b"1\nboom\n").unwrap_err();
+        assert_eq!(panicked, "panicked: boom");
+    }
+}
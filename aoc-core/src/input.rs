@@ -0,0 +1,64 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+
+// Path that stands for standard input
+pub const STDIN: &str = "-";
+
+// Opens a puzzle input, treating '-' as standard input
+pub fn open(path: &str) -> io::Result<Box<dyn BufRead>> {
+    if path == STDIN {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+pub fn read_to_string(path: &str) -> io::Result<String> {
+    let mut contents = String::new();
+    open(path)?.read_to_string(&mut contents)?;
+
+    Ok(contents)
+}
+
+// Strips a UTF-8 byte order mark and converts CRLF line endings to LF. With `trim` it also drops
+// trailing whitespace from every line and blank lines from the end of the input.
+pub fn normalize(contents: &str, trim: bool) -> String {
+    let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+
+    if !trim {
+        return contents.replace("\r\n", "\n");
+    }
+
+    let mut lines: Vec<&str> = contents.lines().map(|line| line.trim_end()).collect();
+
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    let mut normalized = lines.join("\n");
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_bom_and_carriage_returns() {
+        let contents = "\u{feff}1000\r\n2000 \r\n\r\n3000\r\n";
+
+        assert_eq!(normalize(contents, false), "1000\n2000 \n\n3000\n");
+    }
+
+    #[test]
+    fn trims_line_ends_and_trailing_blank_lines() {
+        let contents = "\u{feff}1000\r\n2000 \r\n\r\n3000\r\n  \r\n\r\n";
+
+        assert_eq!(normalize(contents, true), "1000\n2000\n\n3000\n");
+        assert_eq!(normalize("\n \n", true), "");
+    }
+}
@@ -0,0 +1,89 @@
+mod error;
+mod fuzz;
+pub mod input;
+mod parse;
+mod reference;
+mod report;
+mod rng;
+mod run;
+mod solution;
+
+pub use error::{Error, ParseError};
+pub use fuzz::{fuzz, Crash};
+pub use parse::parse_lines;
+pub use reference::{first_disagreement, Disagreement, Mismatch, Reference};
+pub use report::{error_json, run_json, Format};
+pub use rng::Rng;
+pub use run::{answer_types, solve, solve_file, Answers, Run, Timings};
+pub use solution::{Generate, Solution};
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+// Shared entry point for the day binaries: solves the input file given as the only argument,
+// or standard input when it's '-' or missing
+pub fn main<S: Solution>() {
+    let args: Vec<String> = env::args().collect();
+    let usage = || -> ! {
+        eprintln!(
+            "usage: {} [--format <text|json>] [<input file> | -]",
+            program_name(&args)
+        );
+        process::exit(2);
+    };
+
+    let mut format = Format::Text;
+    let mut filename = None;
+
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--format" => match rest.next().map(|f| f.parse()) {
+                Some(Ok(f)) => format = f,
+                _ => usage(),
+            },
+            _ if arg.starts_with("--") || filename.is_some() => usage(),
+            _ => filename = Some(arg.as_str()),
+        }
+    }
+
+    let filename = filename.unwrap_or(input::STDIN);
+
+    if format == Format::Json {
+        match solve_file::<S>(filename) {
+            Ok(run) => println!("{}", run_json(S::DAY, filename, answer_types::<S>(), &run)),
+            Err(e) => {
+                println!("{}", error_json(S::DAY, filename, &e));
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if filename == input::STDIN {
+        println!("Reading input from stdin");
+    } else {
+        println!("Reading input file {}", filename);
+    }
+
+    let input = match S::read_input(filename) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("part 1: {}", S::part1(&input));
+    println!("part 2: {}", S::part2(&input));
+}
+
+fn program_name(args: &[String]) -> String {
+    args.first()
+        .and_then(|arg| Path::new(arg).file_name())
+        .map_or_else(
+            || String::from("day"),
+            |name| name.to_string_lossy().into_owned(),
+        )
+}
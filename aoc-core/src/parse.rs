@@ -0,0 +1,14 @@
+use crate::ParseError;
+use std::str::FromStr;
+
+// Parses every line of the input, tagging a failure with the line it came from
+pub fn parse_lines<T>(contents: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse::<T>().map_err(|e| e.at_line(i)))
+        .collect()
+}
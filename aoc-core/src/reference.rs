@@ -0,0 +1,76 @@
+use crate::{Error, Generate, Rng, Solution};
+use std::ops::Range;
+
+// Slow but obviously correct versions of a day's parts, to check the real ones against
+pub trait Reference: Solution {
+    fn reference_part1(input: &Self::Input) -> Self::Answer1;
+
+    fn reference_part2(input: &Self::Input) -> Self::Answer2;
+}
+
+#[derive(Debug)]
+pub enum Mismatch {
+    Part {
+        part: u32,
+        optimized: String,
+        reference: String,
+    },
+    // The generated input didn't parse, so there was nothing to compare
+    Unparsable(Error),
+}
+
+// A generated input on which the optimized and reference solvers don't agree
+#[derive(Debug)]
+pub struct Disagreement {
+    pub seed: u64,
+    pub input: String,
+    pub mismatch: Mismatch,
+}
+
+// Runs both solvers on inputs generated from each seed in turn and returns the first disagreement
+pub fn first_disagreement<S>(seeds: Range<u64>, size: usize) -> Option<Disagreement>
+where
+    S: Reference + Generate,
+{
+    for seed in seeds {
+        let input = S::generate(&mut Rng::new(seed), size);
+
+        let mismatch = match S::parse(&input) {
+            Ok(parsed) => compare(
+                1,
+                S::part1(&parsed).to_string(),
+                S::reference_part1(&parsed).to_string(),
+            )
+            .or_else(|| {
+                compare(
+                    2,
+                    S::part2(&parsed).to_string(),
+                    S::reference_part2(&parsed).to_string(),
+                )
+            }),
+            Err(e) => Some(Mismatch::Unparsable(e)),
+        };
+
+        if let Some(mismatch) = mismatch {
+            return Some(Disagreement {
+                seed,
+                input,
+                mismatch,
+            });
+        }
+    }
+
+    None
+}
+
+fn compare(part: u32, optimized: String, reference: String) -> Option<Mismatch> {
+    if optimized == reference {
+        None
+    } else {
+        Some(Mismatch::Part {
+            part,
+            optimized,
+            reference,
+        })
+    }
+}
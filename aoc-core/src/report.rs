@@ -0,0 +1,62 @@
+use crate::{Error, ParseError, Run};
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+// Machine-readable description of a single run, as printed by '--format json'
+pub fn run_json(day: u32, input: &str, answer_types: [&str; 2], run: &Run) -> Value {
+    json!({
+        "day": day,
+        "input": input,
+        "part1": {
+            "answer": run.answers.part1,
+            "type": answer_types[0],
+        },
+        "part2": {
+            "answer": run.answers.part2,
+            "type": answer_types[1],
+        },
+        "timings_ns": {
+            "parse": run.timings.parse.as_nanos() as u64,
+            "part1": run.timings.part1.as_nanos() as u64,
+            "part2": run.timings.part2.as_nanos() as u64,
+        },
+    })
+}
+
+// Parse errors also get their line, text and expectation as fields, so callers needn't pick the
+// message apart
+pub fn error_json(day: u32, input: &str, error: &Error) -> Value {
+    let mut report = json!({
+        "day": day,
+        "input": input,
+        "error": error.to_string(),
+    });
+
+    if let Error::Parse(e) = error {
+        report["parse_error"] = json!({
+            "line": e.line,
+            "text": e.text,
+            "expected": e.expected,
+        });
+    }
+
+    report
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(ParseError::new(s, "a format of 'text' or 'json'")),
+        }
+    }
+}
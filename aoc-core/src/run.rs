@@ -0,0 +1,76 @@
+use crate::{input, Error, Solution};
+use std::any;
+use std::time::{Duration, Instant};
+
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+impl Answers {
+    pub fn part(&self, part: u32) -> &str {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+}
+
+// How long each phase of a single run took
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+pub struct Run {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+// Normalizes, parses and solves already-read input, timing each phase
+pub fn solve<S: Solution>(contents: &str) -> Result<Run, Error> {
+    timed::<S>(|| S::parse(&input::normalize(contents, S::TRIM)))
+}
+
+// Reads and solves the named input file, or standard input for '-', through S::read_input, so
+// that days which parse straight off the reader never hold the whole input. The parse time
+// includes reading.
+pub fn solve_file<S: Solution>(filename: &str) -> Result<Run, Error> {
+    timed::<S>(|| S::read_input(filename))
+}
+
+fn timed<S: Solution>(parse: impl FnOnce() -> Result<S::Input, Error>) -> Result<Run, Error> {
+    let start = Instant::now();
+    let input = parse()?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&input).to_string();
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&input).to_string();
+    let part2_time = start.elapsed();
+
+    Ok(Run {
+        answers: Answers { part1, part2 },
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+// Short names of the part 1 and part 2 answer types, e.g. 'u32' or 'String'
+pub fn answer_types<S: Solution>() -> [&'static str; 2] {
+    [
+        short_type_name(any::type_name::<S::Answer1>()),
+        short_type_name(any::type_name::<S::Answer2>()),
+    ]
+}
+
+fn short_type_name(name: &'static str) -> &'static str {
+    name.rsplit("::").next().unwrap_or(name)
+}
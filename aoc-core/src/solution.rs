@@ -0,0 +1,39 @@
+use crate::{input, Error, Rng};
+use std::fmt::Display;
+use std::io::BufRead;
+
+// A single day's puzzle: parse the input once, then solve both parts from the parsed form
+pub trait Solution {
+    const DAY: u32;
+
+    // Whether to trim trailing whitespace and blank lines before parsing. A byte order mark and
+    // CRLF line endings are always removed.
+    const TRIM: bool = true;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    fn read_from<R: BufRead>(mut reader: R) -> Result<Self::Input, Error> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+
+        Self::parse(&input::normalize(&contents, Self::TRIM))
+    }
+
+    // Reads the named input file, or standard input for '-'
+    fn read_input(filename: &str) -> Result<Self::Input, Error> {
+        Self::read_from(input::open(filename)?)
+    }
+}
+
+// Produces random but valid puzzle input of roughly `size` records, for stress testing
+pub trait Generate {
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
@@ -0,0 +1,53 @@
+use crate::days::Day;
+use crate::limits::{self, Limits};
+use crate::pool;
+use crate::table::{format_duration, Table};
+use std::time::Instant;
+
+// Runs every given day concurrently and prints a summary table. Returns true if all succeeded.
+pub fn run_all(inputs: &[(&dyn Day, String)], jobs: usize, limits: Limits) -> bool {
+    let start = Instant::now();
+    let results = pool::map(inputs, jobs, |(day, filename)| {
+        limits::run(*day, filename, limits)
+    });
+    let elapsed = start.elapsed();
+
+    let mut table = Table::new(&[
+        "day", "input", "part 1", "part 2", "parse", "part1", "part2", "status",
+    ]);
+    let mut all_succeeded = true;
+
+    for ((day, filename), result) in inputs.iter().zip(results) {
+        let mut row = vec![day.number().to_string(), filename.clone()];
+
+        match result {
+            Ok(Ok(run)) => {
+                row.extend([
+                    run.answers.part1,
+                    run.answers.part2,
+                    format_duration(run.timings.parse),
+                    format_duration(run.timings.part1),
+                    format_duration(run.timings.part2),
+                    String::from("ok"),
+                ]);
+            }
+            Ok(Err(failure)) => {
+                all_succeeded = false;
+                row.extend(vec![String::new(); 5]);
+                row.push(failure.to_string());
+            }
+            Err(message) => {
+                all_succeeded = false;
+                row.extend(vec![String::new(); 5]);
+                row.push(format!("panicked: {}", message));
+            }
+        }
+
+        table.add_row(row);
+    }
+
+    table.print();
+    println!("Ran {} days in {}", inputs.len(), format_duration(elapsed));
+
+    all_succeeded
+}
@@ -0,0 +1,105 @@
+use crate::days;
+use aoc_core::{Error, ParseError};
+use std::fs;
+
+// A known-correct answer for one part of a day, as recorded in the day's `answers` file
+pub struct KnownAnswer {
+    pub file: String,
+    pub part: u32,
+    pub answer: String,
+}
+
+pub fn answers_path(day: u32) -> String {
+    format!("{}/answers", days::day_dir(day))
+}
+
+pub fn read_known_answers(day: u32) -> Result<Vec<KnownAnswer>, Error> {
+    read_known_answers_from(&answers_path(day))
+}
+
+// Reads lines of the form '<file> <part> <answer>', skipping blank lines and '#' comments
+pub fn read_known_answers_from(path: &str) -> Result<Vec<KnownAnswer>, Error> {
+    let contents = fs::read_to_string(path)?;
+
+    let mut known = Vec::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || ParseError::new(line, "an answer like 'sample 1 24000'").at_line(i);
+
+        let mut fields = line.splitn(3, ' ');
+        let (file, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(file), Some(part), Some(answer)) => (file, part, answer),
+            _ => return Err(error().into()),
+        };
+
+        let part = match part.parse::<u32>() {
+            Ok(part @ 1..=2) => part,
+            _ => return Err(error().into()),
+        };
+
+        known.push(KnownAnswer {
+            file: String::from(file),
+            part,
+            answer: String::from(answer),
+        });
+    }
+
+    Ok(known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    // Writes `contents` to a temporary answers file, reads it back and removes it
+    fn read(name: &str, contents: &str) -> Result<Vec<KnownAnswer>, Error> {
+        let path = env::temp_dir().join(format!("aoc-answers-{}-{}", name, std::process::id()));
+        let path = path.to_str().unwrap();
+
+        fs::write(path, contents).unwrap();
+        let known = read_known_answers_from(path);
+        fs::remove_file(path).unwrap();
+
+        known
+    }
+
+    #[test]
+    fn reads_answers_skipping_comments() {
+        let known = read(
+            "valid",
+            "# file part answer\n\nsample 1 24000\ninput 2 A B\n",
+        )
+        .unwrap();
+
+        assert_eq!(known.len(), 2);
+        assert_eq!((known[0].file.as_str(), known[0].part), ("sample", 1));
+        assert_eq!(known[1].answer, "A B");
+    }
+
+    #[test]
+    fn rejects_malformed_lines_and_parts() {
+        let line_of = |name: &str, contents: &str| match read(name, contents) {
+            Err(Error::Parse(e)) => e.line,
+            _ => panic!("expected a parse error"),
+        };
+
+        assert_eq!(line_of("short", "sample 1 24000\nsample 2\n"), 2);
+        assert_eq!(line_of("part", "# comment\nsample 3 24000\n"), 2);
+        assert_eq!(line_of("nan", "sample one 24000\n"), 1);
+    }
+
+    #[test]
+    fn fails_on_a_missing_file() {
+        let missing = env::temp_dir().join("aoc-answers-that-do-not-exist");
+
+        assert!(matches!(
+            read_known_answers_from(missing.to_str().unwrap()),
+            Err(Error::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+// Minimal command line parsing: positional arguments plus '--name value' options and '--name' flags
+pub struct Args {
+    pub positional: Vec<String>,
+    values: Vec<(String, String)>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    // Returns None when an unknown option is given or an option is missing its value
+    pub fn parse(args: &[String], value_options: &[&str], flag_options: &[&str]) -> Option<Self> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            values: Vec::new(),
+            flags: Vec::new(),
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if value_options.contains(&arg.as_str()) {
+                parsed.values.push((arg.clone(), args.next()?.clone()));
+            } else if flag_options.contains(&arg.as_str()) {
+                parsed.flags.push(arg.clone());
+            } else if arg.starts_with("--") {
+                return None;
+            } else {
+                parsed.positional.push(arg.clone());
+            }
+        }
+
+        Some(parsed)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+}
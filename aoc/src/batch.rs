@@ -0,0 +1,134 @@
+use crate::days::Day;
+use crate::limits::{self, Limits};
+use crate::pool;
+use crate::table::{format_duration, Table};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Expands each argument into input files: a directory yields every file in it, a path whose
+// file name contains '*' or '?' yields the matching files in its directory, anything else is
+// taken as a file
+pub fn expand_inputs(patterns: &[String]) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+
+    for pattern in patterns {
+        let path = Path::new(pattern);
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned());
+
+        if path.is_dir() {
+            files.extend(files_in(path, |_| true)?);
+        } else if let Some(name) = name.filter(|n| n.contains(['*', '?'])) {
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            files.extend(files_in(dir, |file| wildcard_match(&name, file))?);
+        } else {
+            files.push(pattern.clone());
+        }
+    }
+
+    Ok(files)
+}
+
+fn files_in(dir: &Path, matches: impl Fn(&str) -> bool) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned());
+
+        if path.is_file() && name.is_some_and(|name| matches(&name)) {
+            files.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+// Matches a file name against a pattern where '*' is any run of characters and '?' is any one
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // matched[j] is whether the pattern so far matches the first j characters of the name
+    let mut matched = vec![false; name.len() + 1];
+    matched[0] = true;
+
+    for p in pattern {
+        let mut next = vec![false; name.len() + 1];
+
+        for j in 0..=name.len() {
+            next[j] = match p {
+                '*' => matched[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matched[j - 1],
+                c => j > 0 && matched[j - 1] && name[j - 1] == c,
+            };
+        }
+
+        matched = next;
+    }
+
+    matched[name.len()]
+}
+
+// Solves the day against every file, printing one row per file. Returns true if all succeeded.
+pub fn batch(day: &dyn Day, files: &[String], jobs: usize, limits: Limits) -> bool {
+    let results = pool::map(files, jobs, |file| limits::run(day, file, limits));
+
+    let mut table = Table::new(&["file", "part 1", "part 2", "time", "status"]);
+    let mut all_succeeded = true;
+
+    for (file, result) in files.iter().zip(results) {
+        let row = match result {
+            Ok(Ok(run)) => {
+                let time = run.timings.parse + run.timings.part1 + run.timings.part2;
+
+                vec![
+                    file.clone(),
+                    run.answers.part1,
+                    run.answers.part2,
+                    format_duration(time),
+                    String::from("ok"),
+                ]
+            }
+            Ok(Err(failure)) => failed_row(file, failure.to_string()),
+            Err(message) => failed_row(file, format!("panicked: {}", message)),
+        };
+
+        all_succeeded &= row[4] == "ok";
+        table.add_row(row);
+    }
+
+    table.print();
+
+    all_succeeded
+}
+
+fn failed_row(file: &str, status: String) -> Vec<String> {
+    vec![
+        String::from(file),
+        String::new(),
+        String::new(),
+        String::new(),
+        status,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_wildcards() {
+        assert!(wildcard_match("*.txt", "alice.txt"));
+        assert!(wildcard_match("input-?", "input-3"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("*.txt", "alice.txt.bak"));
+        assert!(!wildcard_match("input-?", "input-10"));
+    }
+}
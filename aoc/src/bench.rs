@@ -0,0 +1,264 @@
+use crate::days::Day;
+use crate::table::{format_duration, Table};
+use aoc_core::{input, Error, ParseError, Timings};
+use std::fs;
+use std::time::Duration;
+
+pub const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+pub struct Measurement {
+    pub day: u32,
+    pub phase: String,
+    pub stats: Stats,
+}
+
+// Nearest-rank percentile of already sorted samples, or zero without any
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn stats(samples: &mut [Duration]) -> Stats {
+    samples.sort();
+
+    Stats {
+        min: samples.first().copied().unwrap_or_default(),
+        median: percentile(samples, 0.5),
+        p95: percentile(samples, 0.95),
+    }
+}
+
+fn phase_time(timings: &Timings, phase: usize) -> Duration {
+    match phase {
+        0 => timings.parse,
+        1 => timings.part1,
+        _ => timings.part2,
+    }
+}
+
+// Solves each input `iterations` times after one warm-up run. The input file is read once up front
+// so only parsing and solving are measured. Days that fail are reported and skipped; the returned
+// flag is false if any did.
+pub fn bench(inputs: &[(&dyn Day, String)], iterations: usize) -> (Vec<Measurement>, bool) {
+    let mut measurements = Vec::new();
+    let mut succeeded = true;
+
+    for (day, filename) in inputs {
+        match bench_day(*day, filename, iterations) {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(e) => {
+                eprintln!("Day {} ({}): {}", day.number(), filename, e);
+                succeeded = false;
+            }
+        }
+    }
+
+    (measurements, succeeded)
+}
+
+fn bench_day(day: &dyn Day, filename: &str, iterations: usize) -> Result<Vec<Measurement>, Error> {
+    let contents = input::read_to_string(filename)?;
+
+    day.solve(&contents)?;
+
+    let mut samples: Vec<Vec<Duration>> = vec![Vec::with_capacity(iterations); PHASES.len()];
+
+    for _ in 0..iterations {
+        let run = day.solve(&contents)?;
+
+        for (phase, phase_samples) in samples.iter_mut().enumerate() {
+            phase_samples.push(phase_time(&run.timings, phase));
+        }
+    }
+
+    Ok(samples
+        .iter_mut()
+        .zip(PHASES)
+        .map(|(phase_samples, phase)| Measurement {
+            day: day.number(),
+            phase: String::from(phase),
+            stats: stats(phase_samples),
+        })
+        .collect())
+}
+
+pub fn print(measurements: &[Measurement], baseline: Option<&[Measurement]>) {
+    let mut header = vec!["day", "phase", "min", "median", "p95"];
+    if baseline.is_some() {
+        header.extend(["baseline median", "change"]);
+    }
+
+    let mut table = Table::new(&header);
+
+    for m in measurements {
+        let mut row = vec![
+            m.day.to_string(),
+            m.phase.clone(),
+            format_duration(m.stats.min),
+            format_duration(m.stats.median),
+            format_duration(m.stats.p95),
+        ];
+
+        if let Some(baseline) = baseline {
+            match baseline
+                .iter()
+                .find(|b| b.day == m.day && b.phase == m.phase)
+            {
+                Some(b) => {
+                    let before = b.stats.median.as_secs_f64();
+                    let after = m.stats.median.as_secs_f64();
+                    let change = if before > 0.0 {
+                        format!("{:+.1}%", (after - before) / before * 100.0)
+                    } else {
+                        String::from("-")
+                    };
+
+                    row.push(format_duration(b.stats.median));
+                    row.push(change);
+                }
+                None => row.extend([String::from("-"), String::from("-")]),
+            }
+        }
+
+        table.add_row(row);
+    }
+
+    table.print();
+}
+
+// Baseline files hold one '<day> <phase> <min ns> <median ns> <p95 ns>' line per measurement
+pub fn write_baseline(path: &str, measurements: &[Measurement]) -> Result<(), Error> {
+    let contents: String = measurements
+        .iter()
+        .map(|m| {
+            format!(
+                "{} {} {} {} {}\n",
+                m.day,
+                m.phase,
+                m.stats.min.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.p95.as_nanos()
+            )
+        })
+        .collect();
+
+    fs::write(path, contents)?;
+
+    Ok(())
+}
+
+pub fn read_baseline(path: &str) -> Result<Vec<Measurement>, Error> {
+    let contents = fs::read_to_string(path)?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            parse_measurement(line).ok_or_else(|| {
+                let expected = "a measurement like '1 parse 1200 1500 2100'";
+                ParseError::new(line, expected).at_line(i).into()
+            })
+        })
+        .collect()
+}
+
+fn parse_measurement(line: &str) -> Option<Measurement> {
+    let fields: Vec<&str> = line.split(' ').collect();
+
+    match fields.as_slice() {
+        [day, phase, min, median, p95] if PHASES.contains(phase) => {
+            let nanos = |s: &str| s.parse::<u64>().ok().map(Duration::from_nanos);
+
+            Some(Measurement {
+                day: day.parse().ok()?,
+                phase: String::from(*phase),
+                stats: Stats {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    p95: nanos(p95)?,
+                },
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn takes_nearest_rank_percentiles() {
+        assert_eq!(percentile(&[], 0.5), Duration::ZERO);
+        assert_eq!(percentile(&[ms(7)], 0.0), ms(7));
+        assert_eq!(percentile(&[ms(7)], 0.95), ms(7));
+
+        let sorted: Vec<Duration> = (1..=20).map(ms).collect();
+        assert_eq!(percentile(&sorted, 0.5), ms(10));
+        assert_eq!(percentile(&sorted, 0.95), ms(19));
+
+        let stats = stats(&mut [ms(3), ms(1), ms(2)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(1), ms(2), ms(3)));
+    }
+
+    #[test]
+    fn rejects_malformed_measurements() {
+        assert!(parse_measurement("1 parse 1200 1500 2100").is_some());
+
+        assert!(parse_measurement("1 parse 1200 1500").is_none());
+        assert!(parse_measurement("1 solve 1200 1500 2100").is_none());
+        assert!(parse_measurement("x parse 1200 1500 2100").is_none());
+        assert!(parse_measurement("1 parse 1200 -1 2100").is_none());
+        assert!(parse_measurement("1 parse 1200 1500 2100 9").is_none());
+    }
+
+    #[test]
+    fn reads_back_written_baselines() {
+        let path = env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let measurements = vec![Measurement {
+            day: 7,
+            phase: String::from("part2"),
+            stats: Stats {
+                min: Duration::from_nanos(1200),
+                median: Duration::from_nanos(1500),
+                p95: Duration::from_nanos(2100),
+            },
+        }];
+
+        write_baseline(path, &measurements).unwrap();
+        let read = read_baseline(path);
+
+        fs::write(path, "1 parse 1 2 3\n\n1 part1 oops\n").unwrap();
+        let malformed = read_baseline(path);
+        fs::remove_file(path).unwrap();
+
+        let read = read.unwrap();
+        assert_eq!((read[0].day, read[0].phase.as_str()), (7, "part2"));
+        assert_eq!(read[0].stats.median, Duration::from_nanos(1500));
+        assert_eq!(read[0].stats.p95, Duration::from_nanos(2100));
+
+        match malformed {
+            Err(Error::Parse(e)) => assert_eq!(e.line, 3),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::answers::{self, KnownAnswer};
+use crate::days::{self, Day};
+use crate::table::Table;
+
+// Runs every input listed in each day's answers file and reports whether the results still match.
+// Returns true when every answer matched.
+pub fn check(days: &[&dyn Day]) -> bool {
+    let mut table = Table::new(&["day", "file", "part", "expected", "actual", "status"]);
+    let mut all_passed = true;
+
+    for &day in days {
+        let known = match answers::read_known_answers(day.number()) {
+            Ok(known) => known,
+            Err(e) => {
+                all_passed = false;
+                table.add_row(vec![
+                    day.number().to_string(),
+                    answers::answers_path(day.number()),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    format!("error: {}", e),
+                ]);
+                continue;
+            }
+        };
+
+        all_passed &= check_day(day, &days::day_dir(day.number()), &known, &mut table);
+    }
+
+    table.print();
+
+    all_passed
+}
+
+// Runs the files in `dir` that `known` lists answers for, adding a row per answer to `table`.
+// Returns true when every answer matched.
+fn check_day(day: &dyn Day, dir: &str, known: &[KnownAnswer], table: &mut Table) -> bool {
+    let mut all_passed = true;
+
+    let mut files: Vec<&str> = Vec::new();
+    for k in known {
+        if !files.contains(&k.file.as_str()) {
+            files.push(&k.file);
+        }
+    }
+
+    for file in files {
+        let result = day.run(&format!("{}/{}", dir, file));
+
+        for expected in known.iter().filter(|k| k.file == file) {
+            let (actual, passed) = match &result {
+                Ok(run) => {
+                    let actual = run.answers.part(expected.part);
+                    (String::from(actual), actual == expected.answer)
+                }
+                Err(e) => (format!("error: {}", e), false),
+            };
+
+            all_passed &= passed;
+            table.add_row(row(day, expected, actual, passed));
+        }
+    }
+
+    all_passed
+}
+
+fn row(day: &dyn Day, expected: &KnownAnswer, actual: String, passed: bool) -> Vec<String> {
+    vec![
+        day.number().to_string(),
+        expected.file.clone(),
+        expected.part.to_string(),
+        expected.answer.clone(),
+        actual,
+        String::from(if passed { "pass" } else { "FAIL" }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known(file: &str, part: u32, answer: &str) -> KnownAnswer {
+        KnownAnswer {
+            file: String::from(file),
+            part,
+            answer: String::from(answer),
+        }
+    }
+
+    #[test]
+    fn passes_only_when_every_answer_matches() {
+        let day = days::find(1).unwrap();
+        let mut table = Table::new(&["day", "file", "part", "expected", "actual", "status"]);
+
+        let right = [known("sample", 1, "24000"), known("sample", 2, "45000")];
+        assert!(check_day(day, "../day-01", &right, &mut table));
+
+        let wrong = [known("sample", 1, "24000"), known("sample", 2, "45001")];
+        assert!(!check_day(day, "../day-01", &wrong, &mut table));
+
+        let missing = [known("no-such-file", 1, "24000")];
+        assert!(!check_day(day, "../day-01", &missing, &mut table));
+    }
+}
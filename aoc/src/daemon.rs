@@ -0,0 +1,222 @@
+use crate::days;
+use aoc_core::Error;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+
+// How many parsed inputs to keep. The oldest is dropped first.
+const CACHE_SIZE: usize = 32;
+
+// A parsed input along with the text it came from, since different inputs can share a hash
+struct Cached {
+    input: String,
+    parsed: Box<dyn Any>,
+}
+
+// Solves requests like {"day":7,"part":2,"input":"..."} read one per line, keeping parsed inputs
+// so that asking for the other part, or asking again, skips parsing
+pub struct Daemon {
+    cache: HashMap<(u32, u64), Cached>,
+    order: VecDeque<(u32, u64)>,
+}
+
+impl Daemon {
+    pub fn new() -> Self {
+        Daemon {
+            cache: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    // Answers every line of `reader` with one line of JSON on `writer` until the reader ends
+    pub fn serve<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            writeln!(writer, "{}", self.handle(&line))?;
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn handle(&mut self, line: &str) -> Value {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => return json!({ "id": null, "error": format!("Invalid JSON: {}", e) }),
+        };
+
+        // Echo the id back so that clients can match responses to requests
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+
+        let mut response = match self.answer(&request) {
+            Ok(response) => response,
+            Err(response) => response,
+        };
+        response["id"] = id;
+
+        response
+    }
+
+    fn answer(&mut self, request: &Value) -> Result<Value, Value> {
+        let error = |message: String| json!({ "error": message });
+
+        let day = request["day"]
+            .as_u64()
+            .and_then(|day| u32::try_from(day).ok())
+            .and_then(days::find)
+            .ok_or_else(|| error(format!("Unknown day {}", request["day"])))?;
+
+        let part = match request["part"].as_u64() {
+            Some(part @ 1..=2) => part as u32,
+            _ => return Err(error(String::from("part must be 1 or 2"))),
+        };
+
+        let contents = request["input"]
+            .as_str()
+            .ok_or_else(|| error(String::from("input must be a string")))?;
+
+        let key = (day.number(), content_hash(contents));
+        let cached = self
+            .cache
+            .get(&key)
+            .is_some_and(|entry| entry.input == contents);
+
+        // A panic in the day's code only fails the request rather than ending the daemon
+        if !cached {
+            let parsed = panic::catch_unwind(AssertUnwindSafe(|| day.parse(contents)))
+                .map_err(|_| error(format!("Day {} panicked while parsing", day.number())))?
+                .map_err(|e| match e {
+                    Error::Parse(_) => aoc_core::error_json(day.number(), "-", &e),
+                    _ => error(e.to_string()),
+                })?;
+
+            let entry = Cached {
+                input: String::from(contents),
+                parsed,
+            };
+            self.insert(key, entry);
+        }
+
+        let parsed = &self.cache[&key].parsed;
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| day.solve_part(&**parsed, part)))
+            .map_err(|_| error(format!("Day {} part {} panicked", day.number(), part)))?;
+
+        Ok(json!({
+            "day": day.number(),
+            "part": part,
+            "answer": answer,
+            "cached": cached,
+        }))
+    }
+
+    // Replaces an entry whose hash collided in place, keeping its spot in the eviction order
+    fn insert(&mut self, key: (u32, u64), entry: Cached) {
+        if self.cache.insert(key, entry).is_some() {
+            return;
+        }
+
+        if self.order.len() == CACHE_SIZE {
+            if let Some(oldest) = self.order.pop_front() {
+                self.cache.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key);
+    }
+}
+
+fn content_hash(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../day-07/sample");
+
+    #[test]
+    fn caches_parsed_input_between_parts() {
+        let mut daemon = Daemon::new();
+
+        let request = |part: u32| json!({ "id": part, "day": 7, "part": part, "input": SAMPLE });
+
+        let first = daemon.handle(&request(1).to_string());
+        let second = daemon.handle(&request(2).to_string());
+
+        assert_eq!(first["answer"], "95437");
+        assert_eq!(first["cached"], false);
+        assert_eq!(second["answer"], "24933642");
+        assert_eq!(second["cached"], true);
+        assert_eq!(second["id"], 2);
+    }
+
+    #[test]
+    fn never_answers_from_another_input_with_the_same_hash() {
+        let mut daemon = Daemon::new();
+        let other = "$ cd /\n$ ls\n10 a\n";
+
+        // Cache another input under the hash of the sample, as if the two collided
+        let entry = Cached {
+            input: String::from(other),
+            parsed: days::find(7).unwrap().parse(other).unwrap(),
+        };
+        daemon.insert((7, content_hash(SAMPLE)), entry);
+
+        let response = daemon.handle(&json!({ "day": 7, "part": 1, "input": SAMPLE }).to_string());
+        assert_eq!(response["answer"], "95437");
+        assert_eq!(response["cached"], false);
+
+        let again = daemon.handle(&json!({ "day": 7, "part": 1, "input": SAMPLE }).to_string());
+        assert_eq!(again["cached"], true);
+        assert_eq!(daemon.order.len(), 1);
+    }
+
+    #[test]
+    fn reports_bad_requests_and_parse_errors() {
+        let mut daemon = Daemon::new();
+
+        let response = daemon.handle(r#"{"day":4,"part":1,"input":"2-4,6-8\nx"}"#);
+        assert_eq!(response["parse_error"]["line"], 2);
+
+        assert!(daemon.handle("{").get("error").is_some());
+        assert!(daemon
+            .handle(r#"{"day":4,"part":3,"input":""}"#)
+            .get("error")
+            .is_some());
+        assert!(daemon
+            .handle(r#"{"day":30,"part":1,"input":""}"#)
+            .get("error")
+            .is_some());
+
+        // Would be day 1 if cut down to 32 bits
+        let response = daemon.handle(r#"{"day":4294967297,"part":1,"input":"1\n"}"#);
+        assert_eq!(response["error"], "Unknown day 4294967297");
+    }
+
+    #[test]
+    fn answers_one_line_per_request() {
+        let requests =
+            "{\"day\":4,\"part\":1,\"input\":\"2-8,3-7\\n\"}\n\n{\"day\":4,\"part\":2}\n";
+        let mut output = Vec::new();
+
+        Daemon::new()
+            .serve(requests.as_bytes(), &mut output)
+            .unwrap();
+
+        let lines: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"answer\":\"1\""));
+        assert!(lines[1].contains("error"));
+    }
+}
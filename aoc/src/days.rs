@@ -0,0 +1,125 @@
+use aoc_core::{input, Crash, Disagreement, Error, Generate, Reference, Rng, Run, Solution};
+use std::any::Any;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+// Type-erased view of a day's Solution so the runner can treat every day the same way
+pub trait Day: Sync {
+    fn number(&self) -> u32;
+
+    fn answer_types(&self) -> [&'static str; 2];
+
+    // Parses and solves already-read input, timing each phase
+    fn solve(&self, contents: &str) -> Result<Run, Error>;
+
+    // Normalizes and parses input into the day's own Input type, so it can be kept around and
+    // solved later with solve_part
+    fn parse(&self, contents: &str) -> Result<Box<dyn Any>, Error>;
+
+    // Solves one part from input returned by parse. Panics if it came from another day.
+    fn solve_part(&self, parsed: &dyn Any, part: u32) -> String;
+
+    // Random but valid input for the day, see aoc_core::Generate
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+
+    // First mutated input that makes the day panic, see aoc_core::fuzz
+    fn fuzz(&self, seeds: Range<u64>, corpus: &[&str]) -> Option<Crash>;
+
+    // Reads and solves the named input file, or standard input for '-', see aoc_core::solve_file
+    fn run(&self, filename: &str) -> Result<Run, Error>;
+}
+
+struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution + Generate> Day for Registered<S>
+where
+    S::Input: 'static,
+{
+    fn number(&self) -> u32 {
+        S::DAY
+    }
+
+    fn answer_types(&self) -> [&'static str; 2] {
+        aoc_core::answer_types::<S>()
+    }
+
+    fn solve(&self, contents: &str) -> Result<Run, Error> {
+        aoc_core::solve::<S>(contents)
+    }
+
+    fn run(&self, filename: &str) -> Result<Run, Error> {
+        aoc_core::solve_file::<S>(filename)
+    }
+
+    fn parse(&self, contents: &str) -> Result<Box<dyn Any>, Error> {
+        let parsed = S::parse(&input::normalize(contents, S::TRIM))?;
+
+        Ok(Box::new(parsed))
+    }
+
+    fn solve_part(&self, parsed: &dyn Any, part: u32) -> String {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by another day");
+
+        match part {
+            1 => S::part1(input).to_string(),
+            _ => S::part2(input).to_string(),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        S::generate(rng, size)
+    }
+
+    fn fuzz(&self, seeds: Range<u64>, corpus: &[&str]) -> Option<Crash> {
+        aoc_core::fuzz::<S>(seeds, corpus)
+    }
+}
+
+// Every day the runner knows about, in order
+pub static DAYS: &[&dyn Day] = &[
+    &Registered::<day_01::Day01>(PhantomData),
+    &Registered::<day_02::Day02>(PhantomData),
+    &Registered::<day_03::Day03>(PhantomData),
+    &Registered::<day_04::Day04>(PhantomData),
+    &Registered::<day_05::Day05>(PhantomData),
+    &Registered::<day_06::Day06>(PhantomData),
+    &Registered::<day_07::Day07>(PhantomData),
+];
+
+// A day with reference solvers that its real ones can be checked against
+pub trait Differential: Sync {
+    fn number(&self) -> u32;
+
+    fn first_disagreement(&self, seeds: Range<u64>, size: usize) -> Option<Disagreement>;
+}
+
+impl<S: Reference + Generate> Differential for Registered<S> {
+    fn number(&self) -> u32 {
+        S::DAY
+    }
+
+    fn first_disagreement(&self, seeds: Range<u64>, size: usize) -> Option<Disagreement> {
+        aoc_core::first_disagreement::<S>(seeds, size)
+    }
+}
+
+pub static REFERENCES: &[&dyn Differential] = &[
+    &Registered::<day_03::Day03>(PhantomData),
+    &Registered::<day_06::Day06>(PhantomData),
+    &Registered::<day_07::Day07>(PhantomData),
+];
+
+pub fn find(number: u32) -> Option<&'static dyn Day> {
+    DAYS.iter().find(|day| day.number() == number).copied()
+}
+
+// Directory holding a day's crate and puzzle files, relative to the workspace root
+pub fn day_dir(day: u32) -> String {
+    format!("day-{:02}", day)
+}
+
+pub fn default_input(day: u32) -> String {
+    format!("{}/input", day_dir(day))
+}
@@ -0,0 +1,56 @@
+use crate::days::Differential;
+use aoc_core::Mismatch;
+
+// Compares each day's solvers with its reference ones on `count` generated inputs, printing the
+// first input they disagree on. Returns true if they always agreed.
+pub fn diff(days: &[&dyn Differential], size: usize, seed: u64, count: u64) -> bool {
+    let mut all_agreed = true;
+
+    for day in days {
+        let disagreement = match day.first_disagreement(seed..seed + count, size) {
+            Some(disagreement) => disagreement,
+            None => {
+                println!("Day {}: agreed on {} inputs", day.number(), count);
+                continue;
+            }
+        };
+
+        all_agreed = false;
+
+        match disagreement.mismatch {
+            Mismatch::Part {
+                part,
+                optimized,
+                reference,
+            } => println!(
+                "Day {}: seed {} part {}: optimized {}, reference {}",
+                day.number(),
+                disagreement.seed,
+                part,
+                optimized,
+                reference
+            ),
+            Mismatch::Unparsable(e) => println!(
+                "Day {}: seed {} generated unparsable input: {}",
+                day.number(),
+                disagreement.seed,
+                e
+            ),
+        }
+
+        println!("Input:\n{}", disagreement.input);
+    }
+
+    all_agreed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::REFERENCES;
+
+    #[test]
+    fn every_reference_agrees_on_generated_inputs() {
+        assert!(diff(REFERENCES, 30, 0, 200));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::days::{self, Day};
+use crate::pool;
+use aoc_core::Crash;
+use std::fs;
+use std::ops::Range;
+
+// Fuzzes every day's parser and solvers with `count` mutated inputs each, starting from the day's
+// sample when there is one. Prints the first input per day that made it panic or that it rejected
+// without a line number, and returns true if there were none.
+pub fn fuzz(days: &[&dyn Day], seed: u64, count: u64, jobs: usize) -> bool {
+    let results = pool::map(days, jobs, |&day| {
+        fuzz_day(day, &days::day_dir(day.number()), seed..seed + count)
+    });
+
+    let mut survived = true;
+
+    for (day, result) in days.iter().zip(results) {
+        match result {
+            Ok(None) => println!("Day {}: survived {} inputs", day.number(), count),
+            Ok(Some(crash)) => {
+                survived = false;
+                println!(
+                    "Day {}: seed {} {}",
+                    day.number(),
+                    crash.seed,
+                    crash.message
+                );
+                println!("Input: {:?}", String::from_utf8_lossy(&crash.input));
+            }
+            Err(message) => {
+                survived = false;
+                println!("Day {}: fuzzer panicked: {}", day.number(), message);
+            }
+        }
+    }
+
+    survived
+}
+
+// Fuzzes one day, mutating the sample in `dir` when there is one
+fn fuzz_day(day: &dyn Day, dir: &str, seeds: Range<u64>) -> Option<Crash> {
+    let sample = fs::read_to_string(format!("{}/sample", dir));
+    let corpus: Vec<&str> = sample.iter().map(String::as_str).collect();
+
+    day.fuzz(seeds, &corpus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use std::path::Path;
+
+    #[test]
+    fn every_day_survives_fuzzed_inputs() {
+        let results = pool::map(DAYS, pool::default_jobs(), |&day| {
+            let dir = format!("../{}", days::day_dir(day.number()));
+            assert!(Path::new(&dir).join("sample").exists());
+
+            fuzz_day(day, &dir, 0..3000)
+        });
+
+        for (day, result) in DAYS.iter().zip(results) {
+            if let Some(crash) = result.unwrap() {
+                panic!("day {}: {:?}", day.number(), crash);
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use crate::days::Day;
+use crate::table::format_duration;
+use aoc_core::{Answers, Error, ParseError, Run, Timings};
+use serde_json::Value;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::env;
+use std::fmt;
+use std::io::{self, Read};
+use std::process::{self, Command, Stdio};
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Hidden command the runner re-executes itself with to solve a day under limits
+pub const SOLVE_COMMAND: &str = "__solve";
+
+// Exit code of a child that went over its memory cap
+const EXIT_OUT_OF_MEMORY: i32 = 3;
+
+const MIB: usize = 1024 * 1024;
+
+// Caps on a single day's run. With either set the day runs in a child process that can be killed.
+#[derive(Clone, Copy, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub max_memory: Option<usize>, // Bytes
+}
+
+// A memory cap given in MiB as bytes, or None if that many bytes can't be counted in a usize
+pub fn mib_to_bytes(mib: u64) -> Option<usize> {
+    usize::try_from(mib).ok()?.checked_mul(MIB)
+}
+
+// Why a day produced no answers
+pub enum Failure {
+    Error(Error),
+    Panicked(String),
+    TimedOut(Duration),
+    OutOfMemory(usize),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "error: {}", e),
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {}", format_duration(*timeout))
+            }
+            Failure::OutOfMemory(cap) => write!(f, "out of memory (over {} MiB)", cap / MIB),
+        }
+    }
+}
+
+// Runs a day on the named input, in this process when there are no limits and in a child process
+// that is killed once it goes over them otherwise
+pub fn run(day: &dyn Day, filename: &str, limits: Limits) -> Result<Run, Failure> {
+    if limits.timeout.is_none() && limits.max_memory.is_none() {
+        return day.run(filename).map_err(Failure::Error);
+    }
+
+    let exe = env::current_exe().map_err(|e| Failure::Error(e.into()))?;
+
+    let mut command = Command::new(exe);
+    command
+        .args([SOLVE_COMMAND, &day.number().to_string(), filename])
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(cap) = limits.max_memory {
+        command.args(["--max-memory", &cap.to_string()]);
+    }
+
+    let mut child = command.spawn().map_err(|e| Failure::Error(e.into()))?;
+
+    // Drain the pipes while waiting so a chatty child can't block on a full one
+    let mut stdout = child.stdout.take().unwrap();
+    let mut stderr = child.stderr.take().unwrap();
+    let stdout = thread::spawn(move || read_all(&mut stdout));
+    let stderr = thread::spawn(move || read_all(&mut stderr));
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| Failure::Error(e.into()))? {
+            break status;
+        }
+
+        if let Some(timeout) = limits.timeout.filter(|&timeout| start.elapsed() >= timeout) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Failure::TimedOut(timeout));
+        }
+
+        thread::sleep(Duration::from_millis(5));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    match status.code() {
+        Some(0) | Some(1) => from_report(&stdout),
+        Some(EXIT_OUT_OF_MEMORY) => Err(Failure::OutOfMemory(limits.max_memory.unwrap_or(0))),
+        _ => Err(Failure::Panicked(panic_message(&stderr, status))),
+    }
+}
+
+fn read_all(reader: &mut impl Read) -> String {
+    let mut output = String::new();
+    let _ = reader.read_to_string(&mut output);
+    output
+}
+
+// Rebuilds the run or error from the JSON report the child printed
+fn from_report(report: &str) -> Result<Run, Failure> {
+    let report: Value = serde_json::from_str(report)
+        .map_err(|e| Failure::Panicked(format!("unreadable report: {}", e)))?;
+
+    if let Some(error) = report.get("error") {
+        let error = match &report["parse_error"] {
+            Value::Null => {
+                let message = error.as_str().unwrap_or_default();
+                let message = message
+                    .strip_prefix("Failed to read input: ")
+                    .unwrap_or(message);
+                Error::Io(io::Error::other(message))
+            }
+            e => Error::Parse(ParseError {
+                line: e["line"].as_u64().unwrap_or(0) as usize,
+                text: String::from(e["text"].as_str().unwrap_or_default()),
+                expected: String::from(e["expected"].as_str().unwrap_or_default()),
+            }),
+        };
+
+        return Err(Failure::Error(error));
+    }
+
+    let answer = |part: &str| String::from(report[part]["answer"].as_str().unwrap_or_default());
+    let timing =
+        |phase: &str| Duration::from_nanos(report["timings_ns"][phase].as_u64().unwrap_or(0));
+
+    Ok(Run {
+        answers: Answers {
+            part1: answer("part1"),
+            part2: answer("part2"),
+        },
+        timings: Timings {
+            parse: timing("parse"),
+            part1: timing("part1"),
+            part2: timing("part2"),
+        },
+    })
+}
+
+// The message line the default panic hook printed after "thread '...' panicked at ...:"
+fn panic_message(stderr: &str, status: process::ExitStatus) -> String {
+    let mut lines = stderr.lines();
+
+    match lines.find(|line| line.contains("panicked at")) {
+        Some(_) => String::from(lines.next().unwrap_or_default()),
+        None => format!("solver exited with {}", status),
+    }
+}
+
+// Entry point of the hidden solve command: solves one day in this process and prints its JSON report
+pub fn solve_child(day: &dyn Day, filename: &str, max_memory: Option<usize>) -> ! {
+    if let Some(cap) = max_memory {
+        MEMORY_CAP.store(cap, Ordering::SeqCst);
+    }
+
+    match day.run(filename) {
+        Ok(run) => {
+            println!(
+                "{}",
+                aoc_core::run_json(day.number(), filename, day.answer_types(), &run)
+            );
+            process::exit(0);
+        }
+        Err(e) => {
+            println!("{}", aoc_core::error_json(day.number(), filename, &e));
+            process::exit(1);
+        }
+    }
+}
+
+// Live bytes allocated since the cap was set. Freeing memory allocated before then can take it
+// below zero, which only ever makes the cap a little more generous.
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static MEMORY_CAP: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+// The system allocator, ending the process once the live bytes pass MEMORY_CAP. Only the solve
+// child ever sets a cap, and until then nothing is counted.
+pub struct CappedAllocator;
+
+impl CappedAllocator {
+    fn capped() -> bool {
+        MEMORY_CAP.load(Ordering::Relaxed) != usize::MAX
+    }
+
+    fn reserve(&self, size: usize) {
+        if !Self::capped() {
+            return;
+        }
+
+        let size = size as isize;
+        let allocated = ALLOCATED
+            .fetch_add(size, Ordering::Relaxed)
+            .saturating_add(size);
+
+        if allocated > 0 && allocated as usize > MEMORY_CAP.load(Ordering::Relaxed) {
+            // Straight out of the process: running exit handlers or any other std code from inside
+            // the allocator could allocate again, and _exit is safe for several threads to call
+            unsafe { libc::_exit(EXIT_OUT_OF_MEMORY) }
+        }
+    }
+
+    fn release(&self, size: usize) {
+        if Self::capped() {
+            ALLOCATED.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CappedAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.reserve(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.reserve(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            self.reserve(new_size - layout.size());
+        } else {
+            self.release(layout.size() - new_size);
+        }
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_back_child_reports() {
+        let run = Run {
+            answers: Answers {
+                part1: String::from("95437"),
+                part2: String::from("24933642"),
+            },
+            timings: Timings {
+                parse: Duration::from_nanos(3),
+                part1: Duration::from_nanos(2),
+                part2: Duration::from_nanos(1),
+            },
+        };
+        let report = aoc_core::run_json(7, "sample", ["u64", "u64"], &run).to_string();
+
+        let Ok(read) = from_report(&report) else {
+            panic!("expected a run");
+        };
+        assert_eq!(read.answers.part2, "24933642");
+        assert_eq!(read.timings.parse, Duration::from_nanos(3));
+
+        let error = Error::Parse(ParseError::new("2-x", "a range like '2-4'").at_line(1));
+        let report = aoc_core::error_json(4, "input", &error).to_string();
+
+        match from_report(&report) {
+            Err(Failure::Error(e)) => assert_eq!(e.to_string(), error.to_string()),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn describes_failures() {
+        let timed_out = Failure::TimedOut(Duration::from_millis(1500));
+        let out_of_memory = Failure::OutOfMemory(64 * MIB);
+
+        assert_eq!(timed_out.to_string(), "timed out after 1.50s");
+        assert_eq!(out_of_memory.to_string(), "out of memory (over 64 MiB)");
+    }
+
+    #[test]
+    fn rejects_memory_caps_that_overflow() {
+        assert_eq!(mib_to_bytes(64), Some(64 * MIB));
+        assert_eq!(mib_to_bytes(u64::MAX), None);
+        assert_eq!(mib_to_bytes((usize::MAX / MIB) as u64 + 1), None);
+    }
+}
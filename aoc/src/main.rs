@@ -0,0 +1,556 @@
+mod all;
+mod answers;
+mod args;
+mod batch;
+mod bench;
+mod check;
+mod daemon;
+mod days;
+mod diff;
+mod fuzz;
+mod limits;
+mod mock;
+mod pool;
+mod scaffold;
+mod serve;
+mod stress;
+mod submit;
+mod table;
+mod watch;
+
+use aoc_core::{Format, Rng};
+use args::Args;
+use days::{Day, Differential};
+use limits::{CappedAllocator, Failure, Limits};
+
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: CappedAllocator = CappedAllocator;
+
+const USAGE: &str = "usage:
+    aoc run <day|all> [--input <path|->] [--format <text|json> | --check] [<limits>]
+    aoc bench <day|all> [--input <path|->] [--iterations <n>] [--save <file>] [--baseline <file>]
+    aoc all [--jobs <n>] [<limits>]
+    aoc batch <day> <dir|file|glob>... [--jobs <n>] [<limits>]
+    aoc gen <day> [--size <n>] [--seed <n>]
+    aoc stress <day|all> [--size <n>] [--seed <n>] [--count <n>] [--jobs <n>]
+    aoc diff <day|all> [--size <n>] [--seed <n>] [--count <n>]
+    aoc fuzz <day|all> [--seed <n>] [--count <n>] [--jobs <n>]
+    aoc serve [--port <n>] [--jobs <n>]
+    aoc daemon
+    aoc submit <day> <part> [<answer>] [--url <base url>] [--year <n>]
+    aoc mock-server [--port <n>] [--cooldown <s>]
+    aoc new-day [<day>]
+    aoc watch <day> [--interval <ms>]
+
+limits, which run each day in a child process that is killed when it goes over them:
+    --timeout <ms> --max-memory <MiB>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("all") => all(&args[1..]),
+        Some("batch") => batch(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("stress") => stress(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        Some("fuzz") => fuzz(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        Some("daemon") => daemon(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("mock-server") => mock_server(&args[1..]),
+        Some("new-day") => new_day(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some(limits::SOLVE_COMMAND) => solve_child(&args[1..]),
+        _ => usage(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
+}
+
+fn parse_day(s: &str) -> &'static dyn Day {
+    match s.parse::<u32>().ok().and_then(days::find) {
+        Some(day) => day,
+        None => fail(&format!("Unknown day '{}'", s)),
+    }
+}
+
+// Resolves the '<day|all>' target and optional '--input' into the inputs to run
+fn select_inputs(args: &Args) -> Vec<(&'static dyn Day, String)> {
+    let input = args.value("--input");
+
+    match args.positional.as_slice() {
+        [target] if target == "all" => {
+            if input.is_some() {
+                fail("--input cannot be used with 'all'");
+            }
+
+            days::DAYS
+                .iter()
+                .map(|&day| (day, days::default_input(day.number())))
+                .collect()
+        }
+        [target] => {
+            let day = parse_day(target);
+            let input = input.map_or_else(|| days::default_input(day.number()), String::from);
+
+            vec![(day, input)]
+        }
+        _ => usage(),
+    }
+}
+
+fn run(args: &[String]) {
+    let args = Args::parse(
+        args,
+        &["--input", "--format", "--timeout", "--max-memory"],
+        &["--check"],
+    )
+    .unwrap_or_else(|| usage());
+
+    let format = match args.value("--format").map(|f| f.parse::<Format>()) {
+        None => Format::Text,
+        Some(Ok(format)) => format,
+        Some(Err(e)) => fail(&e.to_string()),
+    };
+
+    if args.flag("--check") {
+        let options = ["--input", "--format", "--timeout", "--max-memory"];
+        if options.iter().any(|option| args.value(option).is_some()) {
+            fail("--input, --format and limits cannot be used with --check");
+        }
+
+        let days: Vec<&dyn Day> = select_inputs(&args).iter().map(|(day, _)| *day).collect();
+
+        if !check::check(&days) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let limits = parse_limits(&args);
+
+    for (day, input) in select_inputs(&args) {
+        run_day(day, &input, format, limits);
+    }
+}
+
+fn run_day(day: &dyn Day, filename: &str, format: Format, limits: Limits) {
+    if format == Format::Text {
+        println!("Day {} ({})", day.number(), filename);
+    }
+
+    match limits::run(day, filename, limits) {
+        Ok(run) if format == Format::Json => {
+            let report = aoc_core::run_json(day.number(), filename, day.answer_types(), &run);
+            println!("{}", report);
+        }
+        Ok(run) => {
+            println!("part 1: {}", run.answers.part1);
+            println!("part 2: {}", run.answers.part2);
+        }
+        Err(Failure::Error(e)) => {
+            if format == Format::Json {
+                println!("{}", aoc_core::error_json(day.number(), filename, &e));
+            } else {
+                eprintln!("{}", e);
+            }
+            process::exit(1);
+        }
+        Err(failure) => {
+            if format == Format::Json {
+                let report = serde_json::json!({
+                    "day": day.number(),
+                    "input": filename,
+                    "error": failure.to_string(),
+                });
+                println!("{}", report);
+            } else {
+                eprintln!("Day {} {}", day.number(), failure);
+            }
+            process::exit(1);
+        }
+    }
+}
+
+fn bench(args: &[String]) {
+    let args = Args::parse(
+        args,
+        &["--input", "--iterations", "--save", "--baseline"],
+        &[],
+    )
+    .unwrap_or_else(|| usage());
+
+    let iterations = match args.value("--iterations").map(|n| n.parse::<usize>()) {
+        None => 100,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => fail("--iterations must be a positive number"),
+    };
+
+    let baseline = args.value("--baseline").map(|path| {
+        bench::read_baseline(path)
+            .unwrap_or_else(|e| fail(&format!("Failed to read baseline {}: {}", path, e)))
+    });
+
+    let (measurements, succeeded) = bench::bench(&select_inputs(&args), iterations);
+    bench::print(&measurements, baseline.as_deref());
+
+    if let Some(path) = args.value("--save") {
+        if let Err(e) = bench::write_baseline(path, &measurements) {
+            eprintln!("Failed to save baseline {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn all(args: &[String]) {
+    let args =
+        Args::parse(args, &["--jobs", "--timeout", "--max-memory"], &[]).unwrap_or_else(|| usage());
+
+    if !args.positional.is_empty() {
+        usage();
+    }
+
+    let jobs = parse_jobs(&args);
+
+    let inputs: Vec<(&dyn Day, String)> = days::DAYS
+        .iter()
+        .map(|&day| (day, days::default_input(day.number())))
+        .collect();
+
+    if !all::run_all(&inputs, jobs, parse_limits(&args)) {
+        process::exit(1);
+    }
+}
+
+fn parse_jobs(args: &Args) -> usize {
+    match args.value("--jobs").map(|n| n.parse::<usize>()) {
+        None => pool::default_jobs(),
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => fail("--jobs must be a positive number"),
+    }
+}
+
+fn batch(args: &[String]) {
+    let args =
+        Args::parse(args, &["--jobs", "--timeout", "--max-memory"], &[]).unwrap_or_else(|| usage());
+
+    let (day, patterns) = match args.positional.split_first() {
+        Some((day, patterns)) if !patterns.is_empty() => (parse_day(day), patterns),
+        _ => usage(),
+    };
+
+    let jobs = parse_jobs(&args);
+
+    let files = batch::expand_inputs(patterns)
+        .unwrap_or_else(|e| fail(&format!("Failed to list input files: {}", e)));
+
+    if files.is_empty() {
+        fail("No input files matched");
+    }
+
+    if !batch::batch(day, &files, jobs, parse_limits(&args)) {
+        process::exit(1);
+    }
+}
+
+fn parse_limits(args: &Args) -> Limits {
+    let positive = |name: &str| match args.value(name).map(|n| n.parse::<u64>()) {
+        None => None,
+        Some(Ok(n)) if n > 0 => Some(n),
+        Some(_) => fail(&format!("{} must be a positive number", name)),
+    };
+
+    Limits {
+        timeout: positive("--timeout").map(Duration::from_millis),
+        max_memory: positive("--max-memory").map(|mib| {
+            limits::mib_to_bytes(mib)
+                .unwrap_or_else(|| fail("--max-memory is more than this machine can address"))
+        }),
+    }
+}
+
+fn parse_number(args: &Args, name: &str, default: u64) -> u64 {
+    match args.value(name).map(|n| n.parse::<u64>()) {
+        None => default,
+        Some(Ok(n)) => n,
+        Some(Err(_)) => fail(&format!("{} must be a number", name)),
+    }
+}
+
+fn generate(args: &[String]) {
+    let args = Args::parse(args, &["--size", "--seed"], &[]).unwrap_or_else(|| usage());
+
+    let day = match args.positional.as_slice() {
+        [day] => parse_day(day),
+        _ => usage(),
+    };
+
+    let size = parse_number(&args, "--size", 100) as usize;
+    let seed = parse_number(&args, "--seed", 0);
+
+    print!("{}", day.generate(&mut Rng::new(seed), size));
+}
+
+fn stress(args: &[String]) {
+    let args = Args::parse(args, &["--size", "--seed", "--count", "--jobs"], &[])
+        .unwrap_or_else(|| usage());
+
+    let days: Vec<&dyn Day> = match args.positional.as_slice() {
+        [target] if target == "all" => days::DAYS.to_vec(),
+        [day] => vec![parse_day(day)],
+        _ => usage(),
+    };
+
+    let size = parse_number(&args, "--size", 1000) as usize;
+    let seed = parse_number(&args, "--seed", 0);
+    let count = parse_number(&args, "--count", 100);
+
+    if !stress::stress(&days, size, seed, count, parse_jobs(&args)) {
+        process::exit(1);
+    }
+}
+
+fn diff(args: &[String]) {
+    let args = Args::parse(args, &["--size", "--seed", "--count"], &[]).unwrap_or_else(|| usage());
+
+    let days: Vec<&dyn Differential> = match args.positional.as_slice() {
+        [target] if target == "all" => days::REFERENCES.to_vec(),
+        [day] => {
+            let number = parse_day(day).number();
+            match days::REFERENCES.iter().find(|d| d.number() == number) {
+                Some(&day) => vec![day],
+                None => fail(&format!("Day {} has no reference solvers", number)),
+            }
+        }
+        _ => usage(),
+    };
+
+    let size = parse_number(&args, "--size", 30) as usize;
+    let seed = parse_number(&args, "--seed", 0);
+    let count = parse_number(&args, "--count", 1000);
+
+    if !diff::diff(&days, size, seed, count) {
+        process::exit(1);
+    }
+}
+
+fn fuzz(args: &[String]) {
+    let args = Args::parse(args, &["--seed", "--count", "--jobs"], &[]).unwrap_or_else(|| usage());
+
+    let days: Vec<&dyn Day> = match args.positional.as_slice() {
+        [target] if target == "all" => days::DAYS.to_vec(),
+        [day] => vec![parse_day(day)],
+        _ => usage(),
+    };
+
+    let seed = parse_number(&args, "--seed", 0);
+    let count = parse_number(&args, "--count", 10000);
+
+    if !fuzz::fuzz(&days, seed, count, parse_jobs(&args)) {
+        process::exit(1);
+    }
+}
+
+fn serve(args: &[String]) {
+    let args = Args::parse(args, &["--port", "--jobs"], &[]).unwrap_or_else(|| usage());
+
+    if !args.positional.is_empty() {
+        usage();
+    }
+
+    let port = match args.value("--port").map(|n| n.parse::<u16>()) {
+        None => 8080,
+        Some(Ok(port)) => port,
+        Some(Err(_)) => fail("--port must be a number between 0 and 65535"),
+    };
+
+    if let Err(e) = serve::serve(port, parse_jobs(&args)) {
+        eprintln!("Failed to start server on port {}: {}", port, e);
+        process::exit(1);
+    }
+}
+
+fn daemon(args: &[String]) {
+    if !args.is_empty() {
+        usage();
+    }
+
+    let stdin = io::stdin().lock();
+    let stdout = io::stdout().lock();
+
+    if let Err(e) = daemon::Daemon::new().serve(stdin, stdout) {
+        eprintln!("Daemon stopped: {}", e);
+        process::exit(1);
+    }
+}
+
+// Runs one day for limits::run in a child process, see limits::solve_child
+fn solve_child(args: &[String]) {
+    let args = Args::parse(args, &["--max-memory"], &[]).unwrap_or_else(|| usage());
+
+    let (day, filename) = match args.positional.as_slice() {
+        [day, filename] => (parse_day(day), filename),
+        _ => usage(),
+    };
+
+    let max_memory = args.value("--max-memory").and_then(|n| n.parse().ok());
+
+    limits::solve_child(day, filename, max_memory);
+}
+
+fn submit(args: &[String]) {
+    let args = Args::parse(args, &["--url", "--year"], &[]).unwrap_or_else(|| usage());
+
+    let (day, part, answer) = match args.positional.as_slice() {
+        [day, part, rest @ ..] if rest.len() <= 1 => {
+            let part = match part.parse::<u32>() {
+                Ok(part @ 1..=2) => part,
+                _ => fail(&format!("Invalid part '{}'", part)),
+            };
+            (parse_day(day), part, rest.first())
+        }
+        _ => usage(),
+    };
+
+    // Without an answer, submit what the day computes for its own input
+    let answer = match answer {
+        Some(answer) => answer.clone(),
+        None => match day.run(&days::default_input(day.number())) {
+            Ok(run) => String::from(run.answers.part(part)),
+            Err(e) => fail(&e.to_string()),
+        },
+    };
+
+    let path = submit::submissions_path(day.number());
+    let submissions = submit::read_submissions(&path)
+        .unwrap_or_else(|e| fail(&format!("Failed to read {}: {}", path, e)));
+
+    if let Some(reason) = submit::already_known(&submissions, part, &answer) {
+        println!("Not submitting: {}", reason);
+        process::exit(1);
+    }
+
+    let session = env::var("AOC_SESSION")
+        .or_else(|_| fs::read_to_string(".aoc-session"))
+        .unwrap_or_else(|_| fail("Set AOC_SESSION or write the session token to .aoc-session"));
+
+    let client = submit::Client {
+        base_url: args
+            .value("--url")
+            .map(String::from)
+            .or_else(|| env::var("AOC_URL").ok())
+            .unwrap_or_else(|| String::from("https://adventofcode.com")),
+        session: String::from(session.trim()),
+        year: parse_number(&args, "--year", 2022) as u32,
+    };
+
+    println!("Day {} part {}: submitting {}", day.number(), part, answer);
+
+    let outcome = match client.submit(day.number(), part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("{}", outcome);
+
+    if outcome.is_final() {
+        let submission = submit::Submission {
+            part,
+            outcome: outcome.clone(),
+            answer,
+        };
+
+        if let Err(e) = submit::record_submission(&path, &submission) {
+            eprintln!("Failed to record the outcome in {}: {}", path, e);
+        }
+    }
+
+    if outcome != submit::Outcome::Right {
+        process::exit(1);
+    }
+}
+
+fn mock_server(args: &[String]) {
+    let args = Args::parse(args, &["--port", "--cooldown"], &[]).unwrap_or_else(|| usage());
+
+    if !args.positional.is_empty() {
+        usage();
+    }
+
+    let port = match args.value("--port").map(|n| n.parse::<u16>()) {
+        None => 8081,
+        Some(Ok(port)) => port,
+        Some(Err(_)) => fail("--port must be a number between 0 and 65535"),
+    };
+    let cooldown = Duration::from_secs(parse_number(&args, "--cooldown", 60));
+
+    let server = tiny_http::Server::http(("127.0.0.1", port)).unwrap_or_else(|e| {
+        eprintln!("Failed to start mock server on port {}: {}", port, e);
+        process::exit(1);
+    });
+
+    println!("Mock answer server on http://127.0.0.1:{}", port);
+    mock::MockSite::from_answer_files(cooldown).serve(&server);
+}
+
+fn new_day(args: &[String]) {
+    let day = match args {
+        [] => scaffold::next_day(),
+        [day] => match day.parse::<u32>() {
+            Ok(day @ 1..=25) => day,
+            _ => fail(&format!("Invalid day '{}'", day)),
+        },
+        _ => usage(),
+    };
+
+    if let Err(e) = scaffold::new_day(day) {
+        eprintln!("Failed to create day {}: {}", day, e);
+        process::exit(1);
+    }
+
+    println!(
+        "Created {} and registered it with the runner",
+        days::day_dir(day)
+    );
+}
+
+fn watch(args: &[String]) {
+    let args = Args::parse(args, &["--interval"], &[]).unwrap_or_else(|| usage());
+
+    let day = match args.positional.as_slice() {
+        [day] => parse_day(day),
+        _ => usage(),
+    };
+
+    let interval = match args.value("--interval").map(|ms| ms.parse::<u64>()) {
+        None => 500,
+        Some(Ok(ms)) if ms > 0 => ms,
+        Some(_) => fail("--interval must be a positive number of milliseconds"),
+    };
+
+    watch::watch(day.number(), Duration::from_millis(interval));
+}
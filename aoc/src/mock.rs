@@ -0,0 +1,181 @@
+use crate::answers;
+use crate::days;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// Stand-in for the puzzle website's answer endpoint, so submit can be tried without going online.
+// It judges answers against the known `input` answers and answers with pages worded like the real
+// ones.
+pub struct MockSite {
+    answers: HashMap<(u32, u32), String>, // (day, part) -> answer
+    cooldown: Duration,                   // How long to refuse answers after a wrong one
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    solved: HashSet<(u32, u32)>,
+    blocked_until: Option<Instant>,
+}
+
+impl MockSite {
+    pub fn new(answers: HashMap<(u32, u32), String>, cooldown: Duration) -> Self {
+        MockSite {
+            answers,
+            cooldown,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    // Judges against every registered day's known answers for its `input` file
+    pub fn from_answer_files(cooldown: Duration) -> Self {
+        let answers = days::DAYS
+            .iter()
+            .flat_map(|day| {
+                let known = answers::read_known_answers(day.number()).unwrap_or_default();
+                known
+                    .into_iter()
+                    .filter(|known| known.file == "input")
+                    .map(move |known| ((day.number(), known.part), known.answer))
+            })
+            .collect();
+
+        MockSite::new(answers, cooldown)
+    }
+
+    // Handles requests until the server is shut down
+    pub fn serve(&self, server: &Server) {
+        while let Ok(request) = server.recv() {
+            self.handle(request);
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let mut body = String::new();
+        let _ = request.as_reader().read_to_string(&mut body);
+
+        let has_session = request.headers().iter().any(|header| {
+            header.field.equiv("Cookie") && header.value.as_str().contains("session=")
+        });
+
+        let (status, page) = if *request.method() != Method::Post {
+            (405, String::from("Method not allowed"))
+        } else if !has_session {
+            (400, String::from("Please log in to get your puzzle input."))
+        } else {
+            self.respond(request.url(), &body)
+        };
+
+        let content_type = Header::from_bytes("Content-Type", "text/html").unwrap();
+        let response = Response::from_string(page)
+            .with_status_code(status)
+            .with_header(content_type);
+
+        let _ = request.respond(response);
+    }
+
+    // Status code and page for a form posted to '/<year>/day/<day>/answer'
+    fn respond(&self, url: &str, body: &str) -> (u16, String) {
+        let day = url
+            .strip_suffix("/answer")
+            .and_then(|url| url.rsplit_once("/day/"))
+            .and_then(|(_, day)| day.parse::<u32>().ok());
+
+        let form: HashMap<String, String> = body
+            .split('&')
+            .filter_map(|field| field.split_once('='))
+            .map(|(name, value)| (url_decode(name), url_decode(value)))
+            .collect();
+
+        let part = form
+            .get("level")
+            .and_then(|level| level.parse::<u32>().ok());
+
+        let (Some(day), Some(part), Some(answer)) = (day, part, form.get("answer")) else {
+            return (404, String::from("404 Not Found"));
+        };
+
+        let Some(expected) = self.answers.get(&(day, part)) else {
+            return (404, String::from("404 Not Found"));
+        };
+
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(left) = state
+            .blocked_until
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+        {
+            return article(&format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have {}s left to wait.",
+                left.as_secs() + 1
+            ));
+        }
+
+        if state.solved.contains(&(day, part)) {
+            return article(
+                "You don't seem to be solving the right level. Did you already complete it?",
+            );
+        }
+
+        if answer.trim() == expected {
+            state.solved.insert((day, part));
+            return article(
+                "That's the right answer! You are one gold star closer to saving Christmas.",
+            );
+        }
+
+        state.blocked_until = Some(Instant::now() + self.cooldown);
+
+        let hint = match (answer.trim().parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => " your answer is too high.",
+            (Ok(answer), Ok(expected)) if answer < expected => " your answer is too low.",
+            _ => "",
+        };
+
+        article(&format!(
+            "That's not the right answer;{} Please wait one minute before trying again.",
+            hint
+        ))
+    }
+}
+
+fn article(text: &str) -> (u16, String) {
+    let page = format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        text
+    );
+
+    (200, page)
+}
+
+// Decodes an application/x-www-form-urlencoded value
+fn url_decode(s: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut input = s.bytes();
+
+    while let Some(b) = input.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = input.by_ref().take(2).collect();
+                let decoded = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                match decoded {
+                    Some(b) => bytes.push(b),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            b => bytes.push(b),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
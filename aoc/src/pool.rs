@@ -0,0 +1,103 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+
+// Name given to the worker threads, which the panic hook below recognizes them by
+const WORKER_NAME: &str = "pool-worker";
+
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// Runs `task` over every item on up to `jobs` worker threads, returning results in item order.
+// A task that panics is reported as Err with its panic message instead of taking the rest down.
+pub fn map<T, R, F>(items: &[T], jobs: usize, task: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> =
+        Mutex::new(items.iter().map(|_| None).collect());
+
+    quiet_workers();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let worker = thread::Builder::new().name(String::from(WORKER_NAME));
+
+            worker
+                .spawn_scoped(scope, || loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result =
+                        panic::catch_unwind(AssertUnwindSafe(|| task(item))).map_err(panic_message);
+
+                    results.lock().unwrap()[index] = Some(result);
+                })
+                .expect("failed to spawn a worker thread");
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err(String::from("never ran"))))
+        .collect()
+}
+
+// A worker's panic message is reported with its result, so the hook only stays quiet for workers
+// and passes every other thread's panics on to the hook that was there before. Installed once for
+// the whole process, so calls to `map` on several threads at a time don't race to swap it.
+fn quiet_workers() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(WORKER_NAME) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolates_panics_and_keeps_order() {
+        let items: Vec<u32> = (0..20).collect();
+
+        let results = map(&items, 4, |&n| {
+            if n == 7 {
+                panic!("bad item {}", n);
+            }
+            n * 2
+        });
+
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[7], Err(String::from("bad item 7")));
+        assert_eq!(results[8], Ok(16));
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 19);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::days::{self, DAYS};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const CARGO_TOML: &str = r#"[package]
+name = "day-__NN__"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+"#;
+
+const MAIN_RS: &str = r#"use day___NN__::Day__NN__;
+
+fn main() {
+    aoc_core::main::<Day__NN__>();
+}
+"#;
+
+const LIB_RS: &str = r#"use aoc_core::{Error, Generate, Rng, Solution};
+
+pub struct Day__NN__;
+
+impl Solution for Day__NN__ {
+    const DAY: u32 = __DAY__;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        Ok(contents.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> Self::Answer1 {
+        0
+    }
+
+    fn part2(_lines: &Self::Input) -> Self::Answer2 {
+        0
+    }
+}
+
+impl Generate for Day__NN__ {
+    // `size` lines of random numbers
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.below(1000)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn solves_sample() {
+        let input = Day__NN__::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day__NN__::part1(&input), 0);
+        assert_eq!(Day__NN__::part2(&input), 0);
+    }
+}
+"#;
+
+const ANSWERS: &str = "# file part answer\n";
+
+fn fill(template: &str, day: u32) -> String {
+    template
+        .replace("__NN__", &format!("{:02}", day))
+        .replace("__DAY__", &day.to_string())
+}
+
+// The day after the last registered one
+pub fn next_day() -> u32 {
+    DAYS.iter().map(|day| day.number()).max().unwrap_or(0) + 1
+}
+
+// Creates day-NN with the standard layout and registers it in the workspace and the runner.
+// Must be run from the workspace root.
+pub fn new_day(day: u32) -> io::Result<()> {
+    let dir = days::day_dir(day);
+    let crate_name = format!("day-{:02}", day);
+    let lib_name = format!("day_{:02}", day);
+
+    if Path::new(&dir).exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir),
+        ));
+    }
+
+    // Check every file we need to edit before creating anything
+    let workspace = fs::read_to_string("Cargo.toml")?;
+    let runner = fs::read_to_string("aoc/Cargo.toml")?;
+    let registry = fs::read_to_string("aoc/src/days.rs")?;
+
+    let workspace = insert_after_last(
+        &workspace,
+        "members = [",
+        "    \"day-",
+        &format!("    \"{}\",", crate_name),
+    )?;
+    let runner = insert_after_last(
+        &runner,
+        "[dependencies]",
+        "day-",
+        &format!("{} = {{ path = \"../{}\" }}", crate_name, crate_name),
+    )?;
+    let registry = insert_after_last(
+        &registry,
+        "pub static DAYS",
+        "    &Registered::<day_",
+        &format!(
+            "    &Registered::<{}::Day{:02}>(PhantomData),",
+            lib_name, day
+        ),
+    )?;
+
+    fs::create_dir_all(format!("{}/src", dir))?;
+    fs::write(format!("{}/Cargo.toml", dir), fill(CARGO_TOML, day))?;
+    fs::write(format!("{}/src/main.rs", dir), fill(MAIN_RS, day))?;
+    fs::write(format!("{}/src/lib.rs", dir), fill(LIB_RS, day))?;
+    fs::write(format!("{}/sample", dir), "")?;
+    fs::write(format!("{}/input", dir), "")?;
+    fs::write(format!("{}/answers", dir), ANSWERS)?;
+
+    fs::write("Cargo.toml", workspace)?;
+    fs::write("aoc/Cargo.toml", runner)?;
+    fs::write("aoc/src/days.rs", registry)?;
+
+    Ok(())
+}
+
+// Inserts a new line after the last line starting with `prefix` in the section opened by the line
+// starting with `section`. The section ends at the next line starting with ']' or '[', so that
+// other lists using the same prefix, like the REFERENCES table after DAYS, are left alone.
+fn insert_after_last(
+    contents: &str,
+    section: &str,
+    prefix: &str,
+    line: &str,
+) -> io::Result<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with(section))
+        .map_or(lines.len(), |i| i + 1);
+    let end = lines[start..]
+        .iter()
+        .position(|l| l.starts_with(']') || l.starts_with('['))
+        .map_or(lines.len(), |i| start + i);
+
+    let index = lines[start..end]
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .map(|i| start + i)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Couldn't find where to register the new day (no '{}')",
+                    prefix.trim()
+                ),
+            )
+        })?;
+
+    lines.insert(index + 1, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_new_days_in_the_days_table() {
+        let registry = include_str!("days.rs");
+        let line = "    &Registered::<day_99::Day99>(PhantomData),";
+
+        let updated =
+            insert_after_last(registry, "pub static DAYS", "    &Registered::<day_", line).unwrap();
+
+        let days_table = updated
+            .split_once("pub static DAYS")
+            .and_then(|(_, rest)| rest.split_once("];"))
+            .unwrap()
+            .0;
+
+        assert!(days_table.ends_with(&format!("{}\n", line)));
+        assert_eq!(updated.matches(line).count(), 1);
+    }
+
+    #[test]
+    fn stops_at_the_end_of_the_section() {
+        let manifest = "[dependencies]\nday-01 = 1\n\n[dev-dependencies]\nday-02 = 2\n";
+
+        let updated = insert_after_last(manifest, "[dependencies]", "day-", "day-03 = 3").unwrap();
+        assert_eq!(
+            updated,
+            "[dependencies]\nday-01 = 1\nday-03 = 3\n\n[dev-dependencies]\nday-02 = 2\n"
+        );
+
+        assert!(insert_after_last(manifest, "members = [", "day-", "x").is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::days;
+use aoc_core::Error;
+use serde_json::{json, Value};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// Answers 'POST /day/{n}' requests on localhost with the JSON report for the posted input, using
+// `jobs` worker threads. Only returns if the server can't be started.
+pub fn serve(port: u16, jobs: usize) -> Result<(), String> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+
+    println!("Listening on http://127.0.0.1:{}", port);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                while let Ok(request) = server.recv() {
+                    handle(request);
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+fn handle(mut request: Request) {
+    let mut body = Vec::new();
+    let (status, report) = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => respond(request.method(), request.url(), &body),
+        Err(e) => (
+            400,
+            json!({ "error": format!("Failed to read request: {}", e) }),
+        ),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(report.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    // The client hanging up early only affects that client
+    let _ = request.respond(response);
+}
+
+// Status code and JSON body for a request, kept apart from the socket handling so it can be tested
+fn respond(method: &Method, url: &str, body: &[u8]) -> (u16, Value) {
+    let error = |status, message: String| (status, json!({ "error": message }));
+
+    let Some(day) = url.strip_prefix("/day/") else {
+        return error(404, format!("No such endpoint {}, try POST /day/<n>", url));
+    };
+
+    let Some(day) = day.parse::<u32>().ok().and_then(days::find) else {
+        return error(404, format!("Unknown day '{}'", day));
+    };
+
+    if *method != Method::Post {
+        return error(
+            405,
+            String::from("Post the puzzle input as the request body"),
+        );
+    }
+
+    let Ok(contents) = std::str::from_utf8(body) else {
+        return error(400, String::from("The puzzle input must be UTF-8"));
+    };
+
+    // The day binaries and runner let a panic end the process; here it only fails the request
+    match panic::catch_unwind(AssertUnwindSafe(|| day.solve(contents))) {
+        Ok(Ok(run)) => (
+            200,
+            aoc_core::run_json(day.number(), url, day.answer_types(), &run),
+        ),
+        Ok(Err(e @ Error::Parse(_))) => (422, aoc_core::error_json(day.number(), url, &e)),
+        Ok(Err(e)) => (500, aoc_core::error_json(day.number(), url, &e)),
+        Err(_) => error(500, format!("Day {} panicked", day.number())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_posted_input() {
+        let (status, report) = respond(&Method::Post, "/day/4", b"2-4,6-8\n2-8,3-7\n");
+
+        assert_eq!(status, 200);
+        assert_eq!(report["part1"]["answer"], "1");
+        assert_eq!(report["part2"]["answer"], "1");
+    }
+
+    #[test]
+    fn reports_parse_errors_with_their_line() {
+        let (status, report) = respond(&Method::Post, "/day/4", b"2-4,6-8\n2-x,3-7\n");
+
+        assert_eq!(status, 422);
+        assert_eq!(report["parse_error"]["line"], 2);
+        assert_eq!(report["parse_error"]["text"], "2-x");
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_methods() {
+        assert_eq!(respond(&Method::Post, "/day/26", b"").0, 404);
+        assert_eq!(respond(&Method::Post, "/days", b"").0, 404);
+        assert_eq!(respond(&Method::Get, "/day/4", b"").0, 405);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::days::Day;
+use crate::pool;
+use crate::table::{format_duration, Table};
+use aoc_core::{Answers, Rng};
+use std::time::{Duration, Instant};
+
+// Solves `count` generated inputs per day, one per seed starting at `seed`, and prints a summary
+// row per day with the first failing seed. Returns true if nothing failed.
+pub fn stress(days: &[&dyn Day], size: usize, seed: u64, count: u64, jobs: usize) -> bool {
+    let seeds: Vec<u64> = (seed..seed + count).collect();
+
+    let mut table = Table::new(&["day", "runs", "failures", "slowest", "first failure"]);
+    let mut all_passed = true;
+
+    for &day in days {
+        let mut failures = 0;
+        let mut first_failure = None;
+        let mut slowest = Duration::ZERO;
+
+        for (seed, result) in seeds.iter().zip(solve_seeds(day, size, &seeds, jobs)) {
+            match result {
+                Ok((_, elapsed)) => slowest = slowest.max(elapsed),
+                Err(failure) => {
+                    failures += 1;
+                    first_failure.get_or_insert(format!("seed {}: {}", seed, failure));
+                }
+            }
+        }
+
+        all_passed &= failures == 0;
+
+        table.add_row(vec![
+            day.number().to_string(),
+            seeds.len().to_string(),
+            failures.to_string(),
+            format_duration(slowest),
+            first_failure.unwrap_or_else(|| String::from("-")),
+        ]);
+    }
+
+    table.print();
+
+    if !all_passed {
+        println!(
+            "Reproduce a failure with: aoc gen <day> --size {} --seed <seed>",
+            size
+        );
+    }
+
+    all_passed
+}
+
+// Generates the input for each seed and solves it, giving the answers and how long solving took,
+// or what went wrong
+pub fn solve_seeds(
+    day: &dyn Day,
+    size: usize,
+    seeds: &[u64],
+    jobs: usize,
+) -> Vec<Result<(Answers, Duration), String>> {
+    let results = pool::map(seeds, jobs, |&seed| {
+        let input = day.generate(&mut Rng::new(seed), size);
+
+        let start = Instant::now();
+        day.solve(&input).map(|run| (run.answers, start.elapsed()))
+    });
+
+    results
+        .into_iter()
+        .map(|result| match result {
+            Ok(Ok(solved)) => Ok(solved),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(message) => Err(format!("panicked: {}", message)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn solves_generated_inputs_the_same_way_every_time() {
+        let seeds: Vec<u64> = (0..20).collect();
+
+        for &day in DAYS {
+            let first = solve_seeds(day, 50, &seeds, 4);
+            let second = solve_seeds(day, 50, &seeds, 4);
+
+            for (seed, results) in seeds.iter().zip(first.iter().zip(&second)) {
+                let (Ok((first, _)), Ok((second, _))) = results else {
+                    let failure = results.0.as_ref().err().or(results.1.as_ref().err());
+                    panic!("day {} seed {}: {}", day.number(), seed, failure.unwrap());
+                };
+
+                assert_eq!(
+                    (&first.part1, &first.part2),
+                    (&second.part1, &second.part2),
+                    "day {} seed {}",
+                    day.number(),
+                    seed
+                );
+            }
+        }
+    }
+}
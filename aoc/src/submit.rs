@@ -0,0 +1,304 @@
+use crate::days;
+use aoc_core::{Error, ParseError};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+// What the website made of a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait(String), // Time left before another answer is accepted, e.g. '42s'
+    AlreadySolved,
+    Unrecognized(String),
+}
+
+impl Outcome {
+    // Whether the answer was judged, so that sending it again can only give the same verdict
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Right | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wait(left) => write!(f, "wait {}", left),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    // Only judged outcomes are ever recorded, so only those are read back
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Outcome::Right),
+            "wrong" => Ok(Outcome::Wrong),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            _ => Err(ParseError::new(s, "right, wrong, too-high or too-low")),
+        }
+    }
+}
+
+// Reads the verdict out of the HTML page the website answers a submission with
+pub fn parse_response(body: &str) -> Outcome {
+    let text = page_text(body);
+
+    if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("answer too recently") {
+        let left = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or("a while", |(left, _)| left);
+
+        Outcome::Wait(String::from(left))
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unrecognized(text.chars().take(200).collect())
+    }
+}
+
+// Text of the page's <article>, or the whole page without one, with the tags stripped
+fn page_text(body: &str) -> String {
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// An answer sent earlier, as recorded in the day's `submissions` file
+pub struct Submission {
+    pub part: u32,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+pub fn submissions_path(day: u32) -> String {
+    format!("{}/submissions", days::day_dir(day))
+}
+
+// Reads lines of the form '<part> <outcome> <answer>'. A missing file means nothing was sent yet.
+pub fn read_submissions(path: &str) -> Result<Vec<Submission>, Error> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut submissions = Vec::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || ParseError::new(line, "a submission like '1 too-high 24000'").at_line(i);
+
+        let mut fields = line.splitn(3, ' ');
+        let (part, outcome, answer) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(part), Some(outcome), Some(answer)) => (part, outcome, answer),
+            _ => return Err(error().into()),
+        };
+
+        let part = match part.parse::<u32>() {
+            Ok(part @ 1..=2) => part,
+            _ => return Err(error().into()),
+        };
+
+        submissions.push(Submission {
+            part,
+            outcome: outcome.parse().map_err(|_| error())?,
+            answer: String::from(answer),
+        });
+    }
+
+    Ok(submissions)
+}
+
+pub fn record_submission(path: &str, submission: &Submission) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(
+        file,
+        "{} {} {}",
+        submission.part, submission.outcome, submission.answer
+    )
+}
+
+// Why sending `answer` again would be pointless given the earlier verdicts, if it would be
+pub fn already_known(submissions: &[Submission], part: u32, answer: &str) -> Option<String> {
+    let number = answer.parse::<i64>().ok();
+
+    for submission in submissions.iter().filter(|s| s.part == part) {
+        let earlier = submission.answer.parse::<i64>().ok();
+
+        let reason = match (&submission.outcome, number, earlier) {
+            (outcome, _, _) if submission.answer == answer => {
+                format!("{} was already submitted and was {}", answer, outcome)
+            }
+            (Outcome::Right, _, _) => format!(
+                "part {} was already solved with {}",
+                part, submission.answer
+            ),
+            (Outcome::TooHigh, Some(n), Some(high)) if n >= high => {
+                format!("{} was too high, so {} is too", high, answer)
+            }
+            (Outcome::TooLow, Some(n), Some(low)) if n <= low => {
+                format!("{} was too low, so {} is too", low, answer)
+            }
+            _ => continue,
+        };
+
+        return Some(reason);
+    }
+
+    None
+}
+
+// Posts answers to the website, or anything that answers like it such as the mock server
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub year: u32,
+}
+
+impl Client {
+    pub fn submit(&self, day: u32, part: u32, answer: &str) -> io::Result<Outcome> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            self.year,
+            day
+        );
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => {
+                    io::Error::other(format!("{} answered with status {}", url, status))
+                }
+                e => io::Error::other(e),
+            })?;
+
+        Ok(parse_response(&response.into_string()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockSite;
+    use std::collections::HashMap;
+    use std::env;
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::Server;
+
+    fn start_mock(cooldown: Duration) -> Client {
+        let answers = HashMap::from([
+            ((1, 1), String::from("70613")),
+            ((5, 1), String::from("VCTFTJQCG")),
+        ]);
+        let site = MockSite::new(answers, cooldown);
+
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        thread::spawn(move || site.serve(&server));
+
+        Client {
+            base_url: format!("http://127.0.0.1:{}", port),
+            session: String::from("test"),
+            year: 2022,
+        }
+    }
+
+    #[test]
+    fn reads_verdicts_from_the_mock_server() {
+        let client = start_mock(Duration::ZERO);
+
+        assert_eq!(client.submit(1, 1, "80000").unwrap(), Outcome::TooHigh);
+        assert_eq!(client.submit(1, 1, "60000").unwrap(), Outcome::TooLow);
+        assert_eq!(client.submit(5, 1, "CMZ").unwrap(), Outcome::Wrong);
+        assert_eq!(client.submit(1, 1, "70613").unwrap(), Outcome::Right);
+        assert_eq!(
+            client.submit(1, 1, "70613").unwrap(),
+            Outcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn is_told_to_wait_after_a_wrong_answer() {
+        let client = start_mock(Duration::from_secs(60));
+
+        assert_eq!(client.submit(5, 1, "CMZ").unwrap(), Outcome::Wrong);
+        assert!(matches!(
+            client.submit(5, 1, "VCTFTJQCG").unwrap(),
+            Outcome::Wait(_)
+        ));
+    }
+
+    #[test]
+    fn records_and_reads_back_submissions() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        for (outcome, answer) in [(Outcome::TooHigh, "80000"), (Outcome::Wrong, "abc")] {
+            let submission = Submission {
+                part: 1,
+                outcome,
+                answer: String::from(answer),
+            };
+            record_submission(path, &submission).unwrap();
+        }
+
+        let submissions = read_submissions(path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(submissions.len(), 2);
+        assert!(already_known(&submissions, 1, "abc").is_some());
+        assert!(already_known(&submissions, 1, "90000").is_some());
+        assert!(already_known(&submissions, 1, "70000").is_none());
+        assert!(already_known(&submissions, 2, "abc").is_none());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::answers::{self, KnownAnswer};
+use crate::days;
+use crate::table::Table;
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const WATCHED_INPUTS: [&str; 2] = ["sample", "input"];
+
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+// Answers from one run of the day binary against one input file
+enum Outcome {
+    Answers([String; 2]),
+    Failed(String),
+}
+
+// Polls the day's sources, inputs and answers file, rebuilding and re-running the day binary
+// against each input whenever anything changes. Runs until interrupted.
+pub fn watch(day: u32, interval: Duration) -> ! {
+    let dir = days::day_dir(day);
+    let mut previous: Vec<Option<Outcome>> = WATCHED_INPUTS.iter().map(|_| None).collect();
+    let mut last_snapshot = None;
+
+    loop {
+        let current = snapshot(Path::new(&dir));
+
+        if last_snapshot.as_ref() != Some(&current) {
+            println!("--- Running day {} ---", day);
+
+            let known = answers::read_known_answers(day).unwrap_or_else(|e| {
+                println!("Not checking answers: {}", e);
+                Vec::new()
+            });
+
+            let mut table = Table::new(&["file", "part", "answer", "previous", "check"]);
+
+            for (file, previous) in WATCHED_INPUTS.iter().zip(previous.iter_mut()) {
+                let outcome = run_day_binary(day, &format!("{}/{}", dir, file));
+                add_rows(&mut table, file, &outcome, previous.as_ref(), &known);
+                *previous = Some(outcome);
+            }
+
+            table.print();
+            last_snapshot = Some(current);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+// Modification times of everything that can change the day's answers
+fn snapshot(dir: &Path) -> Snapshot {
+    let mut paths = Vec::new();
+    collect_files(&dir.join("src"), &mut paths);
+    paths.extend(WATCHED_INPUTS.iter().map(|file| dir.join(file)));
+    paths.push(dir.join("answers"));
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+        Err(_) => return,
+    };
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_files(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+// Builds and runs the day's own binary so source changes are picked up
+fn run_day_binary(day: u32, filename: &str) -> Outcome {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+
+    let output = Command::new(cargo)
+        .args(["run", "--quiet", "-p", &format!("day-{:02}", day), "--"])
+        .args(["--format", "json", filename])
+        .output();
+
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => return Outcome::Failed(format!("Failed to run cargo: {}", e)),
+    };
+
+    let report = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .last()
+        .and_then(|line| serde_json::from_str::<Value>(line).ok());
+
+    match report {
+        Some(report) => match report["error"].as_str() {
+            Some(error) => Outcome::Failed(String::from(error)),
+            None => Outcome::Answers([
+                answer(&report["part1"]["answer"]),
+                answer(&report["part2"]["answer"]),
+            ]),
+        },
+        // No report means the build failed or the solution panicked
+        None => Outcome::Failed(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+    }
+}
+
+fn answer(value: &Value) -> String {
+    value.as_str().map(String::from).unwrap_or_default()
+}
+
+fn add_rows(
+    table: &mut Table,
+    file: &str,
+    outcome: &Outcome,
+    previous: Option<&Outcome>,
+    known: &[KnownAnswer],
+) {
+    let answers = match outcome {
+        Outcome::Answers(answers) => answers,
+        Outcome::Failed(error) => {
+            table.add_row(vec![
+                String::from(file),
+                String::new(),
+                format!("error: {}", error),
+                String::new(),
+                String::new(),
+            ]);
+            return;
+        }
+    };
+
+    for (i, answer) in answers.iter().enumerate() {
+        let part = i as u32 + 1;
+
+        let previous = match previous {
+            Some(Outcome::Answers(previous)) if previous[i] == *answer => String::from("unchanged"),
+            Some(Outcome::Answers(previous)) => previous[i].clone(),
+            _ => String::from("-"),
+        };
+
+        let check = match known.iter().find(|k| k.file == file && k.part == part) {
+            Some(k) if k.answer == *answer => String::from("pass"),
+            Some(k) => format!("FAIL (expected {})", k.answer),
+            None => String::from("-"),
+        };
+
+        table.add_row(vec![
+            String::from(file),
+            part.to_string(),
+            answer.clone(),
+            previous,
+            check,
+        ]);
+    }
+}
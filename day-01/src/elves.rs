@@ -0,0 +1,204 @@
+use aoc_core::{Error, ParseError};
+use std::io::BufRead;
+use std::mem;
+
+// A line of a calorie list, once blank lines at the end of the input are left out
+enum Line {
+    Item { index: usize, calories: u32 },
+    EndOfElf,
+}
+
+// Reads calorie lists one line at a time, telling items from the blank lines that end each elf.
+// Any line that is neither is an error naming its line. Like aoc_core::input::normalize, it skips
+// a byte order mark, line-end whitespace and trailing blank lines. Elves and item_lists both read
+// through it, so they always split the input the same way.
+struct CalorieLines<R> {
+    reader: R,
+    line: String,
+    line_index: usize,
+    blank_lines: usize, // Blank lines seen since the last item, which may turn out to be trailing
+    ends: usize,        // Elves ended by blank lines, waiting to be yielded before `item`
+    item: Option<Line>, // The item read after those blank lines
+    finished: bool,
+}
+
+impl<R: BufRead> CalorieLines<R> {
+    fn new(reader: R) -> Self {
+        CalorieLines {
+            reader,
+            line: String::new(),
+            line_index: 0,
+            blank_lines: 0,
+            ends: 0,
+            item: None,
+            finished: false,
+        }
+    }
+
+    // The line read last, without what gets skipped. It's the line of any item just yielded.
+    fn text(&self) -> &str {
+        let line = self.line.trim_end();
+
+        if self.line_index == 1 {
+            line.strip_prefix('\u{feff}').unwrap_or(line)
+        } else {
+            line
+        }
+    }
+}
+
+// Parses one non-blank line, saying what was wrong with it if it isn't a calorie count
+fn calories(line: &str) -> Result<u32, ParseError> {
+    line.parse::<u32>().map_err(|_| {
+        let expected = if line.bytes().all(|b| b.is_ascii_digit()) {
+            format!("a calorie count of at most {}", u32::MAX)
+        } else {
+            String::from("a calorie count or a blank line")
+        };
+
+        ParseError::new(line, &expected)
+    })
+}
+
+impl<R: BufRead> Iterator for CalorieLines<R> {
+    type Item = Result<Line, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.ends > 0 {
+                self.ends -= 1;
+                return Some(Ok(Line::EndOfElf));
+            }
+
+            if let Some(item) = self.item.take() {
+                return Some(Ok(item));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.finished = true;
+                    return None;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e.into()));
+                }
+            }
+
+            let index = self.line_index;
+            self.line_index += 1;
+
+            if self.text().is_empty() {
+                self.blank_lines += 1;
+                continue;
+            }
+
+            match calories(self.text()) {
+                // The line isn't trailing, so the blank lines before it each ended an elf
+                Ok(calories) => {
+                    self.ends = mem::take(&mut self.blank_lines);
+                    self.item = Some(Line::Item { index, calories });
+                }
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e.at_line(index).into()));
+                }
+            }
+        }
+    }
+}
+
+// Every elf's items, for the few reports that need more than the aggregates
+pub fn item_lists<R: BufRead>(reader: R) -> Result<Vec<Vec<u32>>, Error> {
+    let mut lists = vec![Vec::new()];
+
+    for line in CalorieLines::new(reader) {
+        match line? {
+            Line::Item { calories, .. } => lists.last_mut().unwrap().push(calories),
+            Line::EndOfElf => lists.push(Vec::new()),
+        }
+    }
+
+    Ok(lists)
+}
+
+// What one elf carries, summed up as the items go by rather than kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Elf {
+    pub count: usize,
+    pub sum: u64,
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+}
+
+impl Elf {
+    // Adds an item, or returns None without changing anything if the sum would overflow
+    pub fn add(&mut self, calories: u32) -> Option<u64> {
+        self.sum = self.sum.checked_add(u64::from(calories))?;
+        self.count += 1;
+        self.min = Some(self.min.map_or(calories, |min| min.min(calories)));
+        self.max = Some(self.max.map_or(calories, |max| max.max(calories)));
+
+        Some(self.sum)
+    }
+}
+
+// Yields an Elf per group of items, read one line at a time so that memory use doesn't grow with
+// the input
+pub struct Elves<R> {
+    lines: CalorieLines<R>,
+    current: Elf,
+    finished: bool,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Elves {
+            lines: CalorieLines::new(reader),
+            current: Elf::default(),
+            finished: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            match self.lines.next() {
+                Some(Ok(Line::Item { index, calories })) => {
+                    // Only reachable with billions of items, but a wrong total would be worse
+                    // than an error
+                    if self.current.add(calories).is_none() {
+                        let expected =
+                            format!("an elf carrying at most {} calories in total", u64::MAX);
+                        let error = ParseError::new(self.lines.text(), &expected).at_line(index);
+
+                        self.finished = true;
+                        return Some(Err(error.into()));
+                    }
+                }
+                Some(Ok(Line::EndOfElf)) => return Some(Ok(mem::take(&mut self.current))),
+                Some(Err(e)) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.finished = true;
+                    return Some(Ok(mem::take(&mut self.current)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+mod elves;
+mod plan;
+mod stats;
+
+use aoc_core::{Error, Generate, Rng, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+pub use elves::{item_lists, Elf, Elves};
+pub use plan::{plan, Method, Plan, PlanError, EXACT_LIMIT};
+pub use stats::{stats, Bucket, Stats, PERCENTILES};
+
+// An elf's calorie total along with which elf it is, counting from 1 in input order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub elf: usize,
+    pub total: u64,
+}
+
+// The `k` largest of the elves' totals, most first, with ties going to the earlier elf. Keeps a
+// min-heap of the best `k` seen so far, so it takes O(n log k) and works straight off a stream.
+pub fn top_k<I: IntoIterator<Item = u64>>(totals: I, k: usize) -> Vec<ElfTotal> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // Ordered so that a larger total, then an earlier elf, counts as better. Wrapped in Reverse
+    // to make the heap's top the worst of the kept elves. It grows as elves come in rather than
+    // being sized from `k`, which can be far more than there are elves.
+    let mut heap = BinaryHeap::new();
+
+    for (i, total) in totals.into_iter().enumerate() {
+        let key = Reverse((total, Reverse(i + 1)));
+
+        if heap.len() < k {
+            heap.push(key);
+        } else if heap.peek().is_some_and(|worst| key < *worst) {
+            heap.pop();
+            heap.push(key);
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(elf)))| ElfTotal { elf, total })
+        .collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    // Only each elf's aggregates are kept, never the items themselves
+    type Input = Vec<Elf>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        Elves::new(contents.as_bytes()).collect()
+    }
+
+    // Parses straight off the reader, so that the whole input is never held in memory. Elves
+    // already skips what normalizing would remove.
+    fn read_from<R: BufRead>(reader: R) -> Result<Self::Input, Error> {
+        Elves::new(reader).collect()
+    }
+
+    // Finds the elf with the most calories, returns sum of calories
+    fn part1(elves: &Self::Input) -> Self::Answer1 {
+        elves.iter().map(|elf| elf.sum).max().unwrap_or(0)
+    }
+
+    // Finds the sum of the top 3 elves
+    fn part2(elves: &Self::Input) -> Self::Answer2 {
+        top_k(elves.iter().map(|elf| elf.sum), 3)
+            .iter()
+            .map(|elf| elf.total)
+            .sum()
+    }
+}
+
+impl Generate for Day01 {
+    // `size` elves carrying up to ten items each, some of them carrying nothing
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size)
+            .map(|_| {
+                let items = rng.below(11);
+                (0..items)
+                    .map(|_| format!("{}\n", rng.between(1, 99999)))
+                    .collect()
+            })
+            .collect();
+
+        elves.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn parses_sample_into_elves() {
+        let elves = Day01::parse(SAMPLE).unwrap();
+
+        assert_eq!(elves.len(), 5);
+        assert_eq!(
+            elves[0],
+            Elf {
+                count: 3,
+                sum: 6000,
+                min: Some(1000),
+                max: Some(3000),
+            }
+        );
+        assert_eq!(elves[1].sum, 4000);
+    }
+
+    #[test]
+    fn streams_the_same_elves_as_normalized_input() {
+        let raw = "\u{feff}1\r\n2 \r\n\r\n\r\n3\n\n\n";
+        let normalized = aoc_core::input::normalize(raw, true);
+
+        let streamed: Vec<Elf> = Elves::new(raw.as_bytes()).map(Result::unwrap).collect();
+        let sums: Vec<u64> = streamed.iter().map(|elf| elf.sum).collect();
+
+        // 1 and 2, then the empty elf between the two blank lines, then 3
+        assert_eq!(sums, vec![3, 0, 3]);
+        assert_eq!(streamed, Day01::parse(&normalized).unwrap());
+        assert_eq!(streamed, Day01::read_from(raw.as_bytes()).unwrap());
+
+        let lists = item_lists(raw.as_bytes()).unwrap();
+        assert_eq!(lists, vec![vec![1, 2], vec![], vec![3]]);
+        assert_eq!(Day01::parse("").unwrap(), vec![Elf::default()]);
+    }
+
+    #[test]
+    fn item_lists_split_the_input_like_elves() {
+        for input in ["", "\n\n5\n", "\u{feff}1\r\n\r\n\r\n2\n3 \n\n", "7\nx\n"] {
+            let elves: Result<Vec<Elf>, Error> = Elves::new(input.as_bytes()).collect();
+
+            match (elves, item_lists(input.as_bytes())) {
+                (Ok(elves), Ok(lists)) => {
+                    let sums: Vec<u64> = lists
+                        .iter()
+                        .map(|items| items.iter().map(|&i| u64::from(i)).sum())
+                        .collect();
+                    assert_eq!(sums, elves.iter().map(|elf| elf.sum).collect::<Vec<_>>());
+                }
+                (Err(Error::Parse(a)), Err(Error::Parse(b))) => assert_eq!(a.line, b.line),
+                _ => panic!("{:?} was read differently", input),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_lines_with_their_number() {
+        let line_of = |input: &str| match Day01::parse(input) {
+            Err(Error::Parse(e)) => (e.line, e.text),
+            _ => panic!("expected a parse error"),
+        };
+
+        assert_eq!(line_of("1000\n10O0\n\n3000\n"), (2, String::from("10O0")));
+        assert_eq!(line_of("1\n\n-5\n"), (3, String::from("-5")));
+        assert_eq!(line_of("4294967296\n"), (1, String::from("4294967296")));
+    }
+
+    #[test]
+    fn sums_past_u32_without_overflowing() {
+        let elves = Day01::parse("4294967295\n4294967295\n\n1\n").unwrap();
+
+        assert_eq!(Day01::part1(&elves), 2 * u64::from(u32::MAX));
+
+        let mut elf = Elf {
+            sum: u64::MAX - 1,
+            ..Elf::default()
+        };
+        assert_eq!(elf.add(2), None);
+        assert_eq!(elf.sum, u64::MAX - 1);
+    }
+
+    #[test]
+    fn solves_sample() {
+        let elves = Day01::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day01::part1(&elves), 24000);
+        assert_eq!(Day01::part2(&elves), 45000);
+    }
+
+    #[test]
+    fn finds_top_elves_with_their_positions() {
+        let elves = Day01::parse(SAMPLE).unwrap();
+
+        let totals = || elves.iter().map(|elf| elf.sum);
+
+        let top: Vec<(usize, u64)> = top_k(totals(), 3)
+            .iter()
+            .map(|elf| (elf.elf, elf.total))
+            .collect();
+
+        assert_eq!(top, vec![(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(top_k(totals(), 0), vec![]);
+        assert_eq!(top_k(totals(), 10).len(), 5);
+        assert_eq!(top_k(totals(), usize::MAX).len(), 5);
+    }
+
+    #[test]
+    fn breaks_ties_by_elf_order() {
+        let top: Vec<usize> = top_k([5, 7, 5, 7], 3).iter().map(|elf| elf.elf).collect();
+
+        assert_eq!(top, vec![2, 4, 1]);
+    }
+}
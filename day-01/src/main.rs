@@ -1,56 +1,162 @@
+use aoc_core::{input, Error, Format, Solution};
+use day_01::{Day01, ElfTotal, Elves, Method};
 use std::env;
-use std::fs;
+use std::process;
 
+const USAGE: &str = "usage:
+    day-01 [--format <text|json>] [<input file> | -]
+    day-01 --top <n> [<input file> | -]
+    day-01 --stats [--buckets <n>] [--format <text|json>] [<input file> | -]
+    day-01 --plan [--elves <n>] [--exact] [--format <text|json>] [<input file> | -]";
+
+// Adds day 1's own reports to the shared day binary: '--top <n>' lists the n elves carrying the
+// most calories, '--stats' summarizes how calories are spread and '--plan' hands the items out again
+// as evenly as it can
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let mut top = None;
+    let mut stats = false;
+    let mut buckets = None;
+    let mut plan = false;
+    let mut elves = None;
+    let mut exact = false;
+    let mut format = Format::Text;
+    let mut filename = None;
+
+    let mut rest = args.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--top" => top = Some(number(rest.next())),
+            "--stats" => stats = true,
+            "--buckets" => buckets = Some(positive(rest.next())),
+            "--plan" => plan = true,
+            "--elves" => elves = Some(positive(rest.next())),
+            "--exact" => exact = true,
+            "--format" => match rest.next().map(|f| f.parse()) {
+                Some(Ok(f)) => format = f,
+                _ => usage(),
+            },
+            _ if arg.starts_with("--") || filename.is_some() => usage(),
+            _ => filename = Some(arg.as_str()),
+        }
+    }
+
+    let filename = filename.unwrap_or(input::STDIN);
 
-    println!("Reading input file {}", filename);
-    let elves = read_input(filename);
+    let reports = [top.is_some(), stats, plan];
+    let misplaced = (top.is_some() && format != Format::Text)
+        || (buckets.is_some() && !stats)
+        || ((elves.is_some() || exact) && !plan);
+
+    if reports.iter().filter(|&&report| report).count() > 1 || misplaced {
+        usage();
+    }
 
-    println!("part 1: {}", part1(&elves).unwrap());
-    println!("part 2: {}", part2(&elves).unwrap());
+    let method = if exact { Method::Exact } else { Method::Greedy };
+
+    match top {
+        Some(k) => print_top(filename, k),
+        None if stats => print_stats(filename, buckets.unwrap_or(10), format),
+        None if plan => print_plan(filename, elves, method, format),
+        None => aoc_core::main::<Day01>(),
+    }
 }
 
-fn read_input(filename: &str) -> Vec<Vec<u32>> {
-    let contents = fs::read_to_string(filename).expect("Failed to read file");
-
-    let lines: Vec<Option<u32>> = contents
-        .lines()
-        .map(|l| match l.parse::<u32>() {
-            Ok(n) => Some(n),
-            _ => None,
-        })
-        .collect();
-
-    let mut output = Vec::new();
-    let mut current = Vec::new();
-
-    for line in lines.iter() {
-        match line {
-            Some(n) => current.push(*n),
-            None => {
-                output.push(current);
-                current = Vec::new();
-            }
-        }
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn number(arg: Option<&String>) -> usize {
+    match arg.map(|n| n.parse::<usize>()) {
+        Some(Ok(n)) => n,
+        _ => usage(),
     }
+}
 
-    output.push(current);
+fn positive(arg: Option<&String>) -> usize {
+    match number(arg) {
+        0 => usage(),
+        n => n,
+    }
+}
+
+fn print_top(filename: &str, k: usize) {
+    // Stream the elves straight from the file, so any size of input fits in memory
+    let totals: Result<Vec<ElfTotal>, Error> =
+        input::open(filename)
+            .map_err(Error::from)
+            .and_then(|reader| {
+                let mut error = None;
+                let sums = Elves::new(reader).map_while(|elf| match elf {
+                    Ok(elf) => Some(elf.sum),
+                    Err(e) => {
+                        error = Some(e);
+                        None
+                    }
+                });
 
-    return output;
+                let top = day_01::top_k(sums, k);
+                error.map_or(Ok(top), Err)
+            });
+
+    let top = match totals {
+        Ok(top) => top,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    for (rank, elf) in top.iter().enumerate() {
+        println!("{}. elf {}: {}", rank + 1, elf.elf, elf.total);
+    }
 }
 
-// Finds the elf with the most calories, returns sum of calories
-fn part1(elves: &Vec<Vec<u32>>) -> Option<u32> {
-    elves.iter().map(|elf| elf.iter().sum()).max()
+fn print_stats(filename: &str, buckets: usize, format: Format) {
+    let elves = match Day01::read_input(filename) {
+        Ok(elves) => elves,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let stats = day_01::stats(&elves, buckets);
+
+    match format {
+        Format::Text => print!("{}", stats),
+        Format::Json => println!("{}", stats.to_json()),
+    }
 }
 
-// Finds the sum of the top 3 elves
-fn part2(elves: &Vec<Vec<u32>>) -> Option<u32> {
-    let mut calorie_totals: Vec<u32> = elves.iter().map(|elf| elf.iter().sum()).collect();
+fn print_plan(filename: &str, elves: Option<usize>, method: Method, format: Format) {
+    // The plan needs every item, which the parsed elves don't keep
+    let lists = match input::open(filename)
+        .map_err(Error::from)
+        .and_then(day_01::item_lists)
+    {
+        Ok(lists) => lists,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
-    calorie_totals.sort();
+    // Unless told otherwise, the same elves share the items out among themselves
+    let elves = elves.unwrap_or(lists.len());
 
-    Some(calorie_totals.iter().rev().take(3).sum())
+    let plan = match day_01::plan(&lists, elves, method) {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    match format {
+        Format::Text => print!("{}", plan),
+        Format::Json => println!("{}", plan.to_json()),
+    }
 }
@@ -0,0 +1,332 @@
+use serde_json::{json, Value};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+// Most items the exact planner takes on, since its search can grow exponentially past that
+pub const EXACT_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Greedy, // Largest item first to whoever carries least, within 4/3 of the best possible
+    Exact,  // Branch and bound, for small inputs
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Method::Greedy => write!(f, "greedy"),
+            Method::Exact => write!(f, "exact"),
+        }
+    }
+}
+
+// Why no plan could be made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    NoElves,
+    TooManyElves { elves: usize, most: usize },
+    TooManyItems(usize), // For an exact plan
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::NoElves => write!(f, "the items need at least one elf to carry them"),
+            PlanError::TooManyElves { elves, most } => write!(
+                f,
+                "{} elves is too many, since there are only enough items or elves for {}",
+                elves, most
+            ),
+            PlanError::TooManyItems(items) => write!(
+                f,
+                "an exact plan takes at most {} items, not {}; the greedy plan takes any number",
+                EXACT_LIMIT, items
+            ),
+        }
+    }
+}
+
+// All the items handed out again so that the most any one elf carries is as small as the method
+// manages, along with the most any elf carried before (part 1's answer)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub method: Method,
+    pub elves: Vec<Vec<u32>>,
+    pub before: u64,
+}
+
+impl Plan {
+    pub fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(|items| total(items)).collect()
+    }
+
+    pub fn max(&self) -> u64 {
+        self.totals().into_iter().max().unwrap_or(0)
+    }
+
+    // How much less the most loaded elf carries than before, negative if the plan made it worse
+    pub fn improvement(&self) -> i128 {
+        i128::from(self.before) - i128::from(self.max())
+    }
+
+    pub fn to_json(&self) -> Value {
+        let elves: Vec<Value> = self
+            .elves
+            .iter()
+            .enumerate()
+            .map(|(i, items)| json!({ "elf": i + 1, "total": total(items), "items": items }))
+            .collect();
+
+        json!({
+            "method": self.method.to_string(),
+            "elves": elves,
+            "before": self.before,
+            "after": self.max(),
+            "improvement": self.improvement(),
+        })
+    }
+}
+
+// Plans how to spread the items of every list over `elves` elves. There has to be at least one,
+// and at most one per item or per list, whichever is more, so that a mistyped count can't ask for
+// billions of empty elves.
+pub fn plan(lists: &[Vec<u32>], elves: usize, method: Method) -> Result<Plan, PlanError> {
+    let items = lists.concat();
+    let most = items.len().max(lists.len()).max(1);
+
+    if elves == 0 {
+        return Err(PlanError::NoElves);
+    }
+    if elves > most {
+        return Err(PlanError::TooManyElves { elves, most });
+    }
+
+    let elves = match method {
+        Method::Greedy => greedy(&items, elves),
+        Method::Exact if items.len() > EXACT_LIMIT => {
+            return Err(PlanError::TooManyItems(items.len()))
+        }
+        Method::Exact => exact(&items, elves),
+    };
+
+    Ok(Plan {
+        method,
+        elves,
+        before: lists.iter().map(|items| total(items)).max().unwrap_or(0),
+    })
+}
+
+fn total(items: &[u32]) -> u64 {
+    items.iter().map(|&item| u64::from(item)).sum()
+}
+
+fn sorted_descending(items: &[u32]) -> Vec<u32> {
+    let mut sorted = items.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted
+}
+
+// Longest processing time first: each item, largest first, goes to the elf carrying least so far,
+// the earliest such elf on ties. Takes at least one elf.
+fn greedy(items: &[u32], elves: usize) -> Vec<Vec<u32>> {
+    let mut assigned = vec![Vec::new(); elves];
+    let mut loads: BinaryHeap<_> = (0..assigned.len()).map(|elf| Reverse((0, elf))).collect();
+
+    for item in sorted_descending(items) {
+        let Reverse((load, elf)) = loads.pop().unwrap();
+
+        assigned[elf].push(item);
+        loads.push(Reverse((load + u64::from(item), elf)));
+    }
+
+    assigned
+}
+
+// The best possible spread, found by trying every placement of the items, largest first, and
+// cutting off any branch that can't beat the best found yet. The greedy plan is the starting
+// point, and the search stops as soon as nothing better can exist. Takes at least one elf and at
+// most EXACT_LIMIT items.
+fn exact(items: &[u32], elves: usize) -> Vec<Vec<u32>> {
+    let items = sorted_descending(items);
+
+    let start = greedy(&items, elves);
+    let start_max = start.iter().map(|items| total(items)).max().unwrap_or(0);
+
+    // No elf can carry less than an even share or less than the largest item
+    let lower = total(&items)
+        .div_ceil(elves as u64)
+        .max(items.first().map_or(0, |&item| u64::from(item)));
+
+    let mut search = Search {
+        items: &items,
+        lower,
+        loads: vec![0; elves],
+        placement: vec![0; items.len()],
+        best_max: start_max,
+        best: None,
+    };
+
+    if start_max > lower {
+        search.place(0);
+    }
+
+    let Some(placement) = search.best else {
+        return start;
+    };
+
+    let mut assigned = vec![Vec::new(); elves];
+    for (&item, &elf) in items.iter().zip(&placement) {
+        assigned[elf].push(item);
+    }
+
+    assigned
+}
+
+struct Search<'a> {
+    items: &'a [u32],
+    lower: u64,
+    loads: Vec<u64>,
+    placement: Vec<usize>, // Which elf each item went to on the current branch
+    best_max: u64,
+    best: Option<Vec<usize>>,
+}
+
+impl Search<'_> {
+    // Every load stays below best_max on the way down, so reaching the end means a better plan
+    fn place(&mut self, i: usize) {
+        let Some(&item) = self.items.get(i) else {
+            self.best_max = self.loads.iter().copied().max().unwrap_or(0);
+            self.best = Some(self.placement.clone());
+            return;
+        };
+
+        for elf in 0..self.loads.len() {
+            let load = self.loads[elf];
+
+            // Elves carrying the same are interchangeable, so only the first of them is tried
+            if load + u64::from(item) >= self.best_max || self.loads[..elf].contains(&load) {
+                continue;
+            }
+
+            self.loads[elf] += u64::from(item);
+            self.placement[i] = elf;
+            self.place(i + 1);
+            self.loads[elf] -= u64::from(item);
+
+            if self.best_max <= self.lower {
+                return;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} plan for {} elves:", self.method, self.elves.len())?;
+
+        for (i, items) in self.elves.iter().enumerate() {
+            let listed: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+            writeln!(
+                f,
+                "  elf {}: {} ({})",
+                i + 1,
+                total(items),
+                listed.join(", ")
+            )?;
+        }
+
+        let change = match self.improvement() {
+            0 => String::from("no change"),
+            less if less > 0 => format!("{} less", less),
+            more => format!("{} more", -more),
+        };
+
+        writeln!(
+            f,
+            "most carried: {} before, {} after ({})",
+            self.before,
+            self.max(),
+            change
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item_lists;
+
+    #[test]
+    fn plans_sample_evenly() {
+        let lists = item_lists(include_str!("../sample").as_bytes()).unwrap();
+
+        let plan = plan(&lists, 5, Method::Greedy).unwrap();
+
+        assert_eq!(plan.totals(), vec![11000; 5]);
+        assert_eq!(
+            (plan.before, plan.max(), plan.improvement()),
+            (24000, 11000, 13000)
+        );
+        assert_eq!(plan.elves[0], vec![10000, 1000]);
+        assert_eq!(super::plan(&lists, 5, Method::Exact).unwrap().max(), 11000);
+    }
+
+    #[test]
+    fn finds_better_spreads_than_greedy() {
+        let items = [3, 3, 2, 2, 2];
+
+        // Greedy pairs up the threes first and is left with a 7
+        let greedy_max = greedy(&items, 2).iter().map(|items| total(items)).max();
+        assert_eq!(greedy_max, Some(7));
+
+        let mut exact = exact(&items, 2);
+        exact.sort();
+        assert_eq!(exact, vec![vec![2, 2, 2], vec![3, 3]]);
+    }
+
+    #[test]
+    fn reports_plans_that_make_the_most_carried_worse() {
+        let lists = item_lists(include_str!("../sample").as_bytes()).unwrap();
+
+        for method in [Method::Greedy, Method::Exact] {
+            let plan = plan(&lists, 2, method).unwrap();
+
+            assert_eq!((plan.max(), plan.improvement()), (28000, -4000));
+            assert!(plan
+                .to_string()
+                .ends_with("24000 before, 28000 after (4000 more)\n"));
+            assert_eq!(plan.to_json()["improvement"], -4000);
+        }
+    }
+
+    #[test]
+    fn handles_more_elves_than_items_and_no_items() {
+        let plan = plan(&[vec![5, 9]], 2, Method::Exact).unwrap();
+        assert_eq!(plan.totals(), vec![9, 5]);
+
+        let empty = super::plan(&[vec![]], 1, Method::Greedy).unwrap();
+        assert_eq!(
+            (empty.elves.len(), empty.max(), empty.improvement()),
+            (1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn rejects_impossible_plans() {
+        let lists = [vec![5, 9], vec![1]];
+
+        assert_eq!(plan(&lists, 0, Method::Greedy), Err(PlanError::NoElves));
+        assert_eq!(
+            plan(&lists, 1_000_000_000_000, Method::Greedy),
+            Err(PlanError::TooManyElves {
+                elves: 1_000_000_000_000,
+                most: 3
+            })
+        );
+        assert_eq!(
+            plan(&[vec![1; EXACT_LIMIT + 1]], 2, Method::Exact),
+            Err(PlanError::TooManyItems(EXACT_LIMIT + 1))
+        );
+    }
+}
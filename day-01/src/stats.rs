@@ -0,0 +1,223 @@
+use crate::Elf;
+use serde_json::{json, Value};
+use std::fmt;
+
+// Percentiles of the elves' totals included in the report
+pub const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+
+// Elves whose totals fall in [start, end)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    pub end: u64,
+    pub elves: usize,
+}
+
+// Summary of how calories are spread over the elves
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub empty_elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<(u32, u64)>, // (percentile, total) by the nearest-rank method
+    pub min_items: usize,
+    pub max_items: usize,
+    pub histogram: Vec<Bucket>,
+}
+
+// Builds the report from parsed elves, splitting the range of totals into `buckets` equal-width
+// histogram buckets
+pub fn stats(elves: &[Elf], buckets: usize) -> Stats {
+    let mut totals: Vec<u64> = elves.iter().map(|elf| elf.sum).collect();
+    totals.sort_unstable();
+
+    let n = totals.len();
+    let sum: u64 = totals.iter().sum();
+
+    let mean = if n == 0 { 0.0 } else { sum as f64 / n as f64 };
+    let median = match n {
+        0 => 0.0,
+        _ if n % 2 == 1 => totals[n / 2] as f64,
+        _ => (totals[n / 2 - 1] + totals[n / 2]) as f64 / 2.0,
+    };
+
+    let percentiles = PERCENTILES
+        .iter()
+        .filter(|_| n > 0)
+        .map(|&p| {
+            let rank = (p as usize * n).div_ceil(100).max(1);
+            (p, totals[rank - 1])
+        })
+        .collect();
+
+    Stats {
+        elves: n,
+        empty_elves: elves.iter().filter(|elf| elf.count == 0).count(),
+        items: elves.iter().map(|elf| elf.count).sum(),
+        mean,
+        median,
+        percentiles,
+        min_items: elves.iter().map(|elf| elf.count).min().unwrap_or(0),
+        max_items: elves.iter().map(|elf| elf.count).max().unwrap_or(0),
+        histogram: histogram(&totals, buckets),
+    }
+}
+
+fn histogram(sorted_totals: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted_totals.first(), sorted_totals.last()) else {
+        return Vec::new();
+    };
+
+    let buckets = buckets.max(1) as u64;
+    let width = (max - min + 1).div_ceil(buckets);
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|i| Bucket {
+            start: min + i * width,
+            end: min + (i + 1) * width,
+            elves: 0,
+        })
+        .take_while(|bucket| bucket.start <= max)
+        .collect();
+
+    for total in sorted_totals {
+        histogram[((total - min) / width) as usize].elves += 1;
+    }
+
+    histogram
+}
+
+impl Stats {
+    pub fn to_json(&self) -> Value {
+        let percentiles: serde_json::Map<String, Value> = self
+            .percentiles
+            .iter()
+            .map(|(p, total)| (format!("p{}", p), json!(total)))
+            .collect();
+
+        let histogram: Vec<Value> = self
+            .histogram
+            .iter()
+            .map(
+                |bucket| json!({ "start": bucket.start, "end": bucket.end, "elves": bucket.elves }),
+            )
+            .collect();
+
+        json!({
+            "elves": self.elves,
+            "empty_elves": self.empty_elves,
+            "totals": {
+                "mean": self.mean,
+                "median": self.median,
+                "percentiles": percentiles,
+            },
+            "items": {
+                "total": self.items,
+                "mean_per_elf": self.items as f64 / self.elves.max(1) as f64,
+                "min_per_elf": self.min_items,
+                "max_per_elf": self.max_items,
+            },
+            "histogram": histogram,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "elves: {} ({} carrying nothing)",
+            self.elves, self.empty_elves
+        )?;
+        writeln!(
+            f,
+            "items: {} ({:.1} per elf, {} to {})",
+            self.items,
+            self.items as f64 / self.elves.max(1) as f64,
+            self.min_items,
+            self.max_items
+        )?;
+        writeln!(f, "mean total: {:.1}", self.mean)?;
+        writeln!(f, "median total: {:.1}", self.median)?;
+
+        for (p, total) in &self.percentiles {
+            writeln!(f, "p{}: {}", p, total)?;
+        }
+
+        let widest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let label_width = self
+            .histogram
+            .iter()
+            .map(|b| format!("{}-{}", b.start, b.end - 1).len())
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f, "histogram:")?;
+        for bucket in &self.histogram {
+            // Bars are scaled so that the fullest bucket is 40 wide
+            let bar = "#".repeat((bucket.elves * 40).div_ceil(widest.max(1)));
+            let label = format!("{}-{}", bucket.start, bucket.end - 1);
+
+            writeln!(
+                f,
+                "  {:>w$} | {:<5} {}",
+                label,
+                bucket.elves,
+                bar,
+                w = label_width
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_core::Solution;
+
+    #[test]
+    fn summarizes_sample() {
+        let elves = Day01::parse(include_str!("../sample")).unwrap();
+
+        let stats = stats(&elves, 3);
+
+        assert_eq!((stats.elves, stats.empty_elves, stats.items), (5, 0, 10));
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(stats.percentiles[0], (10, 4000));
+        assert_eq!(stats.percentiles[5], (99, 24000));
+        assert_eq!((stats.min_items, stats.max_items), (1, 3));
+
+        let counts: Vec<usize> = stats.histogram.iter().map(|b| b.elves).collect();
+        assert_eq!(counts, vec![3, 1, 1]);
+        assert_eq!(stats.histogram[0].start, 4000);
+        assert_eq!(stats.histogram[2].end, 24001);
+    }
+
+    #[test]
+    fn counts_empty_elves_and_handles_equal_totals() {
+        let elves = Day01::parse("\n\n5\n").unwrap();
+
+        let stats = stats(&elves, 4);
+
+        assert_eq!((stats.elves, stats.empty_elves), (3, 2));
+        assert_eq!(stats.median, 0.0);
+        // Buckets past the largest total are left out
+        assert_eq!(stats.histogram.len(), 3);
+
+        let equal = super::stats(&Day01::parse("7\n\n7\n").unwrap(), 10);
+        assert_eq!(
+            equal.histogram,
+            vec![Bucket {
+                start: 7,
+                end: 8,
+                elves: 2
+            }]
+        );
+    }
+}
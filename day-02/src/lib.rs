@@ -0,0 +1,138 @@
+use aoc_core::{parse_lines, Error, Generate, ParseError, Rng, Solution};
+use std::str::FromStr;
+
+pub struct Round(pub char, pub char);
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut chars = line.chars();
+
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(opponent @ 'A'..='C'), Some(' '), Some(response @ 'X'..='Z'), None) => {
+                Ok(Round(opponent, response))
+            }
+            _ => Err(ParseError::new(line, "a round like 'A Y'")),
+        }
+    }
+}
+
+pub fn rps_score_part1(round: &Round) -> u32 {
+    match round {
+        Round(c, 'X') => {
+            1 + match c {
+                'B' => 0,
+                'C' => 6,
+                _ => 3,
+            }
+        }
+        Round(c, 'Y') => {
+            2 + match c {
+                'A' => 6,
+                'C' => 0,
+                _ => 3,
+            }
+        }
+        Round(c, 'Z') => {
+            3 + match c {
+                'A' => 0,
+                'B' => 6,
+                _ => 3,
+            }
+        }
+        _ => 0,
+    }
+}
+
+pub fn rps_score_part2(round: &Round) -> u32 {
+    match round {
+        Round(c, 'X') => match c {
+            'A' => 3,
+            'B' => 1,
+            _ => 2,
+        },
+        Round(c, 'Y') => {
+            3 + match c {
+                'A' => 1,
+                'B' => 2,
+                _ => 3,
+            }
+        }
+        Round(c, 'Z') => {
+            6 + match c {
+                'A' => 2,
+                'B' => 3,
+                _ => 1,
+            }
+        }
+        _ => 0,
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Round>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        Ok(parse_lines(contents)?)
+    }
+
+    fn part1(rounds: &Self::Input) -> Self::Answer1 {
+        rounds.iter().map(rps_score_part1).sum()
+    }
+
+    fn part2(rounds: &Self::Input) -> Self::Answer2 {
+        rounds.iter().map(rps_score_part2).sum()
+    }
+}
+
+impl Generate for Day02 {
+    // `size` rounds of random moves
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let opponent = rng.pick(&['A', 'B', 'C']);
+                let response = rng.pick(&['X', 'Y', 'Z']);
+                format!("{} {}\n", opponent, response)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn scores_rounds() {
+        assert_eq!(rps_score_part1(&Round('A', 'Y')), 8);
+        assert_eq!(rps_score_part2(&Round('A', 'Y')), 4);
+    }
+
+    #[test]
+    fn solves_sample() {
+        let rounds = Day02::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day02::part1(&rounds), 15);
+        assert_eq!(Day02::part2(&rounds), 12);
+    }
+
+    #[test]
+    fn rejects_unknown_moves() {
+        assert!("A Q".parse::<Round>().is_err());
+        assert!("AY".parse::<Round>().is_err());
+
+        match Day02::parse("A Y\nB Q\n") {
+            Err(Error::Parse(e)) => assert_eq!((e.line, e.text.as_str()), (2, "B Q")),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
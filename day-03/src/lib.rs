@@ -0,0 +1,245 @@
+mod reference;
+
+use aoc_core::{parse_lines, Error, Generate, ParseError, Rng, Solution};
+use std::str::FromStr;
+
+pub struct Sack {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+impl FromStr for Sack {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if !line.len().is_multiple_of(2) || !line.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(ParseError::new(
+                line,
+                "an even number of items, each a letter a-z or A-Z",
+            ));
+        }
+
+        let compartment_size = line.len() / 2;
+
+        let sack = Sack {
+            left: line
+                .chars()
+                .take(compartment_size)
+                .map(char_to_priority)
+                .collect(),
+            right: line
+                .chars()
+                .skip(compartment_size)
+                .map(char_to_priority)
+                .collect(),
+        };
+
+        // Part 1 scores the item packed in both compartments, so there has to be one
+        if sack.find_duplicates().is_empty() {
+            return Err(ParseError::new(line, "an item type in both compartments"));
+        }
+
+        Ok(sack)
+    }
+}
+
+impl Sack {
+    pub fn contains(&self, item: u32) -> bool {
+        self.left.contains(&item) || self.right.contains(&item)
+    }
+
+    pub fn find_duplicates(&self) -> Vec<u32> {
+        let num_items = self.left.len();
+        let mut sorted_left = self.left.clone();
+        sorted_left.sort();
+        let mut sorted_right = self.right.clone();
+        sorted_right.sort();
+
+        let mut output: Vec<u32> = Vec::new();
+
+        let mut left_pointer: usize = 0;
+        let mut right_pointer: usize = 0;
+        let mut last_found_number: u32 = 0;
+        while left_pointer < num_items && right_pointer < num_items {
+            let left = sorted_left[left_pointer];
+            let right = sorted_right[right_pointer];
+            if left == right {
+                // Avoid duplicates
+                if left > last_found_number {
+                    output.push(left);
+                    last_found_number = left;
+                }
+                left_pointer += 1;
+                right_pointer += 1;
+            } else if left < right {
+                left_pointer += 1;
+            } else {
+                right_pointer += 1;
+            }
+        }
+
+        output
+    }
+}
+
+pub fn char_to_priority(c: char) -> u32 {
+    if c.is_uppercase() {
+        ((c as u8) - 64u8) as u32 + 26
+    } else {
+        ((c as u8) - 96u8) as u32
+    }
+}
+
+pub fn priority_to_char(priority: u32) -> char {
+    if priority > 26 {
+        (b'A' + (priority - 27) as u8) as char
+    } else {
+        (b'a' + (priority - 1) as u8) as char
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<Sack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        Ok(parse_lines(contents)?)
+    }
+
+    fn part1(sacks: &Self::Input) -> Self::Answer1 {
+        sacks
+            .iter()
+            .map(|round| *round.find_duplicates().first().unwrap())
+            .sum()
+    }
+
+    fn part2(sacks: &Self::Input) -> Self::Answer2 {
+        // Find the item shared by all elves
+        (0..sacks.len() / 3)
+            .map(|i| {
+                // Return the badge for each group
+                // Get the group
+                let sack_group: Vec<&Sack> = sacks.iter().skip(i * 3).take(3).collect();
+
+                for i in 1..53 {
+                    if sack_group[0].contains(i)
+                        && sack_group[1].contains(i)
+                        && sack_group[2].contains(i)
+                    {
+                        return i;
+                    }
+                }
+
+                0
+            })
+            .sum()
+    }
+}
+
+impl Generate for Day03 {
+    // `size` sacks, rounded up to whole groups of three. Every sack has exactly one item type in
+    // both compartments and every group has exactly one item type in common.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut output = String::new();
+
+        for _ in 0..size.div_ceil(3) {
+            let badge = rng.between(1, 52) as u32;
+
+            // Keep every other item type out of at least one sack so only the badge is shared
+            let mut allowed: Vec<Vec<u32>> = vec![Vec::new(); 3];
+            for item in (1..=52).filter(|&item| item != badge) {
+                let excluded = rng.index(3);
+
+                for (sack, items) in allowed.iter_mut().enumerate() {
+                    if sack != excluded {
+                        items.push(item);
+                    }
+                }
+            }
+
+            for items in allowed.iter_mut() {
+                output.push_str(&generate_sack(rng, badge, items));
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
+
+fn generate_sack(rng: &mut Rng, badge: u32, items: &mut [u32]) -> String {
+    let compartment_size = rng.between(2, 16) as usize;
+    rng.shuffle(items);
+
+    // One item type goes in both compartments, the rest are split between them
+    let duplicate = if rng.chance(1, 4) { badge } else { items[0] };
+    let others: Vec<u32> = items.iter().copied().filter(|&i| i != duplicate).collect();
+    let (left_pool, right_pool) = others.split_at(others.len() / 2);
+
+    let mut left = vec![duplicate];
+    let mut right = vec![duplicate];
+
+    if duplicate != badge {
+        if rng.chance(1, 2) {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+
+    while left.len() < compartment_size {
+        left.push(*rng.pick(left_pool));
+    }
+    while right.len() < compartment_size {
+        right.push(*rng.pick(right_pool));
+    }
+
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+
+    left.iter()
+        .chain(&right)
+        .map(|&p| priority_to_char(p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn finds_duplicate_items() {
+        let sack: Sack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse().unwrap();
+
+        assert_eq!(sack.find_duplicates(), vec![char_to_priority('p')]);
+        assert_eq!(char_to_priority('a'), 1);
+        assert_eq!(char_to_priority('Z'), 52);
+    }
+
+    #[test]
+    fn solves_sample() {
+        let sacks = Day03::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day03::part1(&sacks), 157);
+        assert_eq!(Day03::part2(&sacks), 70);
+    }
+
+    #[test]
+    fn rejects_odd_sized_sacks() {
+        assert!("abc".parse::<Sack>().is_err());
+        assert!("ab1c".parse::<Sack>().is_err());
+    }
+
+    #[test]
+    fn rejects_sacks_without_a_shared_item() {
+        assert!("abcd".parse::<Sack>().is_err());
+        assert!("".parse::<Sack>().is_err());
+    }
+}
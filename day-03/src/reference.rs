@@ -0,0 +1,46 @@
+use crate::{Day03, Sack};
+use aoc_core::Reference;
+
+// Every item type found in both compartments, by comparing all pairs of items
+fn shared_items(sack: &Sack) -> Vec<u32> {
+    let mut shared = Vec::new();
+
+    for left in &sack.left {
+        for right in &sack.right {
+            if left == right && !shared.contains(left) {
+                shared.push(*left);
+            }
+        }
+    }
+
+    shared
+}
+
+fn in_sack(sack: &Sack, item: u32) -> bool {
+    sack.left.iter().chain(&sack.right).any(|&i| i == item)
+}
+
+impl Reference for Day03 {
+    fn reference_part1(sacks: &Self::Input) -> Self::Answer1 {
+        sacks
+            .iter()
+            .map(|sack| shared_items(sack).into_iter().min().unwrap_or(0))
+            .sum()
+    }
+
+    fn reference_part2(sacks: &Self::Input) -> Self::Answer2 {
+        sacks
+            .chunks_exact(3)
+            .map(|group| {
+                group[0]
+                    .left
+                    .iter()
+                    .chain(&group[0].right)
+                    .filter(|&&item| in_sack(&group[1], item) && in_sack(&group[2], item))
+                    .min()
+                    .copied()
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,144 @@
+use aoc_core::{Error, Generate, ParseError, Rng, Solution};
+use std::str::FromStr;
+
+pub struct Range(pub u32, pub u32);
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseError::new(s, "a range like '2-4'");
+
+        let (start, end) = s.split_once('-').ok_or_else(error)?;
+        let start = start.parse::<u32>().map_err(|_| error())?;
+        let end = end.parse::<u32>().map_err(|_| error())?;
+
+        if start > end {
+            return Err(ParseError::new(
+                s,
+                "a range that doesn't end before it starts",
+            ));
+        }
+
+        Ok(Range(start, end))
+    }
+}
+
+impl Range {
+    pub fn contains(&self, other: &Range) -> bool {
+        self.0 <= other.0 && other.1 <= self.1
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.contains(other)
+            || (self.0 <= other.0 && self.1 >= other.0)
+            || (self.0 <= other.1 && self.1 >= other.1)
+            || other.contains(self)
+    }
+}
+
+pub fn parse_range_pair(line: &str) -> Result<(Range, Range), ParseError> {
+    let (first, second) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(line, "two ranges like '2-4,6-8'"))?;
+
+    Ok((first.parse()?, second.parse()?))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<(Range, Range)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        let range_pairs = contents
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_range_pair(l).map_err(|e| e.at_line(i)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(range_pairs)
+    }
+
+    fn part1(range_pairs: &Self::Input) -> Self::Answer1 {
+        range_pairs
+            .iter()
+            .map(|range_pair| {
+                if range_pair.0.contains(&range_pair.1) || range_pair.1.contains(&range_pair.0) {
+                    1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn part2(range_pairs: &Self::Input) -> Self::Answer2 {
+        range_pairs
+            .iter()
+            .map(|range_pair| {
+                if range_pair.0.overlaps(&range_pair.1) {
+                    1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+}
+
+impl Generate for Day04 {
+    // `size` pairs of ranges within 1-99
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let first = random_range(rng);
+                let second = random_range(rng);
+                format!("{},{}\n", first, second)
+            })
+            .collect()
+    }
+}
+
+fn random_range(rng: &mut Rng) -> String {
+    let start = rng.between(1, 99);
+    let end = rng.between(start, 99);
+
+    format!("{}-{}", start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn compares_ranges() {
+        let (first, second) = parse_range_pair("2-8,3-7").unwrap();
+
+        assert!(first.contains(&second));
+        assert!(!second.contains(&first));
+        assert!(Range(5, 7).overlaps(&Range(7, 9)));
+        assert!(!Range(2, 4).overlaps(&Range(6, 8)));
+    }
+
+    #[test]
+    fn solves_sample() {
+        let range_pairs = Day04::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day04::part1(&range_pairs), 2);
+        assert_eq!(Day04::part2(&range_pairs), 4);
+    }
+
+    #[test]
+    fn rejects_malformed_ranges() {
+        assert!("2-x".parse::<Range>().is_err());
+        assert!("4-2".parse::<Range>().is_err());
+        assert!(parse_range_pair("2-4").is_err());
+    }
+}
@@ -0,0 +1,332 @@
+use aoc_core::{Error, Generate, ParseError, Rng, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::str::FromStr;
+
+pub struct MoveOperation {
+    pub n: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl FromStr for MoveOperation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        }
+
+        let error = || ParseError::new(s, "an operation like 'move 1 from 2 to 3'");
+
+        let captures = RE.captures(s).ok_or_else(error)?;
+        let number = |i: usize| captures[i].parse::<usize>().map_err(|_| error());
+
+        Ok(MoveOperation {
+            n: number(1)?,
+            from: number(2)?,
+            to: number(3)?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Cargo {
+    pub lanes: Vec<Vec<char>>,
+}
+
+impl Cargo {
+    pub fn with_lanes(n: usize) -> Self {
+        let mut lanes = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            lanes.push(Vec::new());
+        }
+
+        Cargo { lanes }
+    }
+
+    pub fn push_to_lane(&mut self, lane: usize, item: char) {
+        self.lanes[lane].push(item);
+    }
+
+    pub fn move_items(&mut self, n: usize, from: usize, to: usize) {
+        if self.lanes[from].len() < n {
+            panic!(
+                "Tried to move {} from lane {} when it only has {} items",
+                n,
+                from,
+                self.lanes[from].len()
+            );
+        }
+
+        for _ in 0..n {
+            let item = self.lanes[from].pop().unwrap();
+            self.lanes[to].push(item);
+        }
+    }
+
+    pub fn move_items_batch(&mut self, n: usize, from: usize, to: usize) {
+        if self.lanes[from].len() < n {
+            panic!(
+                "Tried to move {} from lane {} when it only has {} items",
+                n,
+                from,
+                self.lanes[from].len()
+            );
+        }
+
+        let mut items = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            items.push(self.lanes[from].pop().unwrap());
+        }
+
+        for _ in 0..n {
+            self.lanes[to].push(items.pop().unwrap());
+        }
+    }
+
+    pub fn reverse_lanes(&mut self) {
+        for lane in &mut self.lanes {
+            lane.reverse();
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = (Cargo, Vec<MoveOperation>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        let mut lines = contents.lines().enumerate();
+
+        // Collect the diagram rows up to the row of lane numbers, which says how many lanes there are
+        let mut rows = Vec::new();
+        let num_lanes = loop {
+            match lines.next() {
+                Some((_, line)) if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) => {
+                    break line.split_whitespace().count();
+                }
+                Some((_, line)) => rows.push(line),
+                None => {
+                    let error = ParseError::new("", "a row of lane numbers like ' 1   2   3'");
+                    return Err(error.at_line(rows.len()).into());
+                }
+            }
+        };
+
+        let mut cargo = Cargo::with_lanes(num_lanes);
+
+        for line in rows {
+            let mut chars = line.chars();
+            for i in 0..num_lanes {
+                chars.next(); // lanes have the format '[X] ' repeated. Skip the first '['
+                              // Read the character
+                if let Some(item) = chars.next() {
+                    if item != ' ' {
+                        cargo.push_to_lane(i, item)
+                    }
+                }
+
+                // Skip the remaining '] '
+                chars.next();
+                chars.next();
+            }
+        }
+
+        // We read the lanes in reverse so need to flip them
+        cargo.reverse_lanes();
+
+        // Both parts move the same number of crates, so the lane heights can be played out here
+        // to reject moves that take more crates than a lane holds
+        let mut heights: Vec<usize> = cargo.lanes.iter().map(Vec::len).collect();
+
+        let mut operations = Vec::new();
+        // Now read the operations
+        for (i, line) in lines {
+            if !line.is_empty() {
+                let operation: MoveOperation =
+                    line.parse().map_err(|e: ParseError| e.at_line(i))?;
+
+                let lanes = 1..=num_lanes;
+                if !lanes.contains(&operation.from) || !lanes.contains(&operation.to) {
+                    let expected = format!("lanes between 1 and {}", num_lanes);
+                    return Err(ParseError::new(line, &expected).at_line(i).into());
+                }
+
+                let (from, to) = (operation.from - 1, operation.to - 1);
+                if heights[from] < operation.n {
+                    let expected = format!("a move of at most {} crates", heights[from]);
+                    return Err(ParseError::new(line, &expected).at_line(i).into());
+                }
+                heights[from] -= operation.n;
+                heights[to] += operation.n;
+
+                operations.push(operation)
+            }
+        }
+
+        Ok((cargo, operations))
+    }
+
+    fn part1((c, operations): &Self::Input) -> Self::Answer1 {
+        let mut cargo = c.clone();
+
+        operations
+            .iter()
+            .for_each(|op| cargo.move_items(op.n, op.from - 1, op.to - 1));
+
+        cargo
+            .lanes
+            .iter()
+            .map(|lane| lane.last().unwrap_or(&' '))
+            .collect()
+    }
+
+    fn part2((c, operations): &Self::Input) -> Self::Answer2 {
+        let mut cargo = c.clone();
+
+        operations
+            .iter()
+            .for_each(|op| cargo.move_items_batch(op.n, op.from - 1, op.to - 1));
+
+        cargo
+            .lanes
+            .iter()
+            .map(|lane| lane.last().unwrap_or(&' '))
+            .collect()
+    }
+}
+
+impl Generate for Day05 {
+    // One to nine lanes, some of which may start empty, followed by `size` valid moves
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let num_lanes = rng.between(1, 9) as usize;
+
+        let mut lanes: Vec<Vec<char>> = (0..num_lanes)
+            .map(|_| {
+                (0..rng.below(9))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        if lanes.iter().all(|lane| lane.is_empty()) {
+            lanes[0].push('A');
+        }
+
+        let mut output = String::new();
+
+        let height = lanes.iter().map(Vec::len).max().unwrap_or(0);
+        for row in (0..height).rev() {
+            let cells: Vec<String> = lanes
+                .iter()
+                .map(|lane| match lane.get(row) {
+                    Some(item) => format!("[{}]", item),
+                    None => String::from("   "),
+                })
+                .collect();
+
+            output.push_str(&cells.join(" "));
+            output.push('\n');
+        }
+
+        let numbers: Vec<String> = (1..=num_lanes).map(|n| format!(" {} ", n)).collect();
+        output.push_str(&numbers.join(" "));
+        output.push_str("\n\n");
+
+        // Play the moves out so every one of them takes from a lane that has enough crates
+        for _ in 0..size {
+            let non_empty: Vec<usize> = (0..num_lanes)
+                .filter(|&lane| !lanes[lane].is_empty())
+                .collect();
+
+            let from = *rng.pick(&non_empty);
+            let to = match num_lanes {
+                1 => from,
+                _ => {
+                    let to = rng.index(num_lanes - 1);
+                    if to >= from {
+                        to + 1
+                    } else {
+                        to
+                    }
+                }
+            };
+            let n = rng.between(1, lanes[from].len() as u64) as usize;
+
+            let remaining = lanes[from].len() - n;
+            let moved: Vec<char> = lanes[from].drain(remaining..).collect();
+            lanes[to].extend(moved);
+
+            output.push_str(&format!("move {} from {} to {}\n", n, from + 1, to + 1));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn parses_sample_into_lanes() {
+        let (cargo, operations) = Day05::parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            cargo.lanes,
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+        );
+        assert_eq!(operations.len(), 4);
+        assert_eq!(
+            (operations[0].n, operations[0].from, operations[0].to),
+            (1, 2, 1)
+        );
+    }
+
+    #[test]
+    fn solves_sample() {
+        let input = Day05::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day05::part1(&input), "CMZ");
+        assert_eq!(Day05::part2(&input), "MCD");
+    }
+
+    #[test]
+    fn counts_lanes_from_the_number_row() {
+        let trimmed = aoc_core::input::normalize(SAMPLE, true);
+        let (cargo, _) = Day05::parse(&trimmed).unwrap();
+
+        assert_eq!(trimmed.lines().next(), Some("    [D]"));
+        assert_eq!(cargo.lanes.len(), 3);
+    }
+
+    #[test]
+    fn rejects_moves_to_missing_lanes() {
+        let input = SAMPLE.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+
+        match Day05::parse(&input) {
+            Err(Error::Parse(e)) => assert_eq!(e.line, 9),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn rejects_moves_of_more_crates_than_a_lane_holds() {
+        let input = SAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+
+        match Day05::parse(&input) {
+            Err(Error::Parse(e)) => assert_eq!(e.line, 7),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
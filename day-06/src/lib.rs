@@ -0,0 +1,119 @@
+mod reference;
+
+use aoc_core::{Error, Generate, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        match contents.lines().next() {
+            Some(line) => Ok(String::from(line)),
+            None => Err(ParseError::new("", "a datastream").at_line(0).into()),
+        }
+    }
+
+    fn part1(sequence: &Self::Input) -> Self::Answer1 {
+        let mut counts: HashMap<char, u32> = HashMap::new();
+
+        const MARKER_SIZE: usize = 4;
+
+        // Add first 3 characters to the set
+        sequence.chars().take(MARKER_SIZE - 1).for_each(|c| {
+            counts.insert(c, counts.get(&c).unwrap_or(&0) + 1);
+        });
+
+        for (i, c) in sequence.chars().enumerate().skip(MARKER_SIZE - 1) {
+            // Add character to set
+            counts.insert(c, counts.get(&c).unwrap_or(&0) + 1);
+
+            // Is everything unique?
+            if counts.values().all(|&count| count <= 1) {
+                return (i + 1) as u32;
+            }
+
+            let removal_char = &sequence.chars().nth(i - (MARKER_SIZE - 1)).unwrap();
+
+            counts.insert(*removal_char, counts.get(removal_char).unwrap_or(&1) - 1);
+        }
+
+        0
+    }
+
+    fn part2(sequence: &Self::Input) -> Self::Answer2 {
+        let mut counts: HashMap<char, u32> = HashMap::new();
+
+        const MARKER_SIZE: usize = 14;
+
+        // Add first 3 characters to the set
+        sequence.chars().take(MARKER_SIZE - 1).for_each(|c| {
+            counts.insert(c, counts.get(&c).unwrap_or(&0) + 1);
+        });
+
+        for (i, c) in sequence.chars().enumerate().skip(MARKER_SIZE - 1) {
+            // Add character to set
+            counts.insert(c, counts.get(&c).unwrap_or(&0) + 1);
+
+            // Is everything unique?
+            if counts.values().all(|&count| count <= 1) {
+                return (i + 1) as u32;
+            }
+
+            let removal_char = &sequence.chars().nth(i - (MARKER_SIZE - 1)).unwrap();
+            counts.insert(*removal_char, counts.get(removal_char).unwrap_or(&1) - 1);
+        }
+
+        0
+    }
+}
+
+impl Generate for Day06 {
+    // A datastream of `size` characters (at least 14) that is guaranteed to contain a marker
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(14);
+
+        // A small alphabet keeps markers from appearing by chance right at the start
+        let mut stream: Vec<char> = (0..size)
+            .map(|_| (b'a' + rng.below(8) as u8) as char)
+            .collect();
+
+        let mut marker: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut marker);
+
+        let start = rng.index(size - 13);
+        stream[start..start + 14].copy_from_slice(&marker[..14]);
+
+        let mut output: String = stream.into_iter().collect();
+        output.push('\n');
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn solves_sample() {
+        let sequence = Day06::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day06::part1(&sequence), 5);
+        assert_eq!(Day06::part2(&sequence), 23);
+    }
+
+    #[test]
+    fn finds_markers() {
+        let sequence = String::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        assert_eq!(Day06::part1(&sequence), 7);
+        assert_eq!(Day06::part2(&sequence), 19);
+    }
+}
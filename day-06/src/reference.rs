@@ -0,0 +1,37 @@
+use crate::Day06;
+use aoc_core::Reference;
+
+// Position just after the first window of `size` characters that are all different, checking
+// every window from scratch
+fn find_marker(sequence: &str, size: usize) -> u32 {
+    let chars: Vec<char> = sequence.chars().collect();
+
+    if chars.len() < size {
+        return 0;
+    }
+
+    for end in size..=chars.len() {
+        let window = &chars[end - size..end];
+
+        let unique = window
+            .iter()
+            .enumerate()
+            .all(|(i, c)| !window[i + 1..].contains(c));
+
+        if unique {
+            return end as u32;
+        }
+    }
+
+    0
+}
+
+impl Reference for Day06 {
+    fn reference_part1(sequence: &Self::Input) -> Self::Answer1 {
+        find_marker(sequence, 4)
+    }
+
+    fn reference_part2(sequence: &Self::Input) -> Self::Answer2 {
+        find_marker(sequence, 14)
+    }
+}
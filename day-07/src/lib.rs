@@ -0,0 +1,413 @@
+mod reference;
+
+use aoc_core::{Error, Generate, ParseError, Rng, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub enum Command {
+    CD(String),
+    LS,
+}
+
+pub struct LsResult {
+    pub identifier: String,
+    pub size: u64,
+}
+
+impl LsResult {
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(\d+|dir) ([\w\.]+)$").unwrap();
+        }
+
+        let error = || ParseError::new(line, "a listing like 'dir a' or '1234 b.txt'");
+
+        let captures = RE.captures(line).ok_or_else(error)?;
+
+        let size = match &captures[1] {
+            "dir" => 0,
+            size => size.parse::<u64>().map_err(|_| error())?,
+        };
+
+        Ok(LsResult {
+            identifier: String::from(&captures[2]),
+            size,
+        })
+    }
+}
+
+pub struct FileSystemNode {
+    pub identifier: String,
+    pub children: Vec<usize>, // Indexes of the child nodes in the node array
+    pub size: u64,            // Size of this particular node
+    pub total_size: Option<u64>, // Size of this node plus total size of all children
+    pub parent: Option<usize>, // Index of the parent node in the node array
+}
+
+impl FileSystemNode {
+    pub fn with_identifier(name: &str) -> Self {
+        FileSystemNode {
+            identifier: String::from(name),
+            children: Vec::new(),
+            size: 0,
+            total_size: None,
+            parent: None,
+        }
+    }
+
+    pub fn add_child(&mut self, child_index: usize) {
+        self.children.push(child_index)
+    }
+
+    pub fn is_dir(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+pub struct FileSystem {
+    pub nodes: Vec<FileSystemNode>, // Root is always index 0
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub fn new() -> Self {
+        FileSystem {
+            nodes: vec![FileSystemNode::with_identifier("/")],
+        }
+    }
+
+    pub fn get_node(&self, current_node: usize, name: &str) -> Option<usize> {
+        let node = &self.nodes[current_node];
+
+        if name == ".." {
+            node.parent
+        } else {
+            node.children
+                .iter()
+                .find(|&node_index| self.nodes[*node_index].identifier == name)
+                .copied()
+        }
+    }
+
+    pub fn add_child(&mut self, current_node: usize, child: FileSystemNode) {
+        let child_index = self.nodes.len();
+        self.nodes[current_node].add_child(child_index);
+
+        let mut child = child;
+        child.parent = Some(current_node);
+
+        self.nodes.push(child);
+    }
+
+    // Fills in every node's total size and returns the one asked for. Children are always added
+    // after their parent, so going through the nodes backwards reaches each one after all of its
+    // children, without recursing as deep as the tree goes.
+    pub fn compute_total_size(&mut self, working_node: usize) -> u64 {
+        for index in (0..self.nodes.len()).rev() {
+            let node = &self.nodes[index];
+
+            if node.total_size.is_some() {
+                continue;
+            }
+
+            let total_size = node.size
+                + node
+                    .children
+                    .iter()
+                    .map(|&child| self.nodes[child].total_size.unwrap_or_default())
+                    .sum::<u64>();
+
+            // Cache it
+            self.nodes[index].total_size = Some(total_size);
+        }
+
+        self.nodes[working_node].total_size.unwrap_or_default()
+    }
+
+    // fn print(&self) {
+    //     for (index, node) in self.nodes.iter().enumerate() {
+    //         println!(
+    //             "{}: '{}', {:?}, {:?}, {}, {:?}",
+    //             index, node.identifier, node.parent, node.children, node.size, node.total_size
+    //         );
+    //     }
+    // }
+}
+
+pub fn get_command(line: &str) -> Result<Command, ParseError> {
+    if line.starts_with("$ ls") {
+        Ok(Command::LS)
+    } else if let Some(name) = line.strip_prefix("$ cd ") {
+        Ok(Command::CD(String::from(name)))
+    } else {
+        Err(ParseError::new(line, "a command like '$ ls' or '$ cd a'"))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = FileSystem;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(contents: &str) -> Result<Self::Input, Error> {
+        let mut lines = contents.lines().enumerate().skip(1); // Skip the first '/' line
+
+        let mut file_system = FileSystem::new();
+        let mut working_node_index: usize = 0;
+        let mut listed_size: u64 = 0; // Bounds every directory total, so checking it rules out overflow
+
+        while let Some((i, line)) = lines.next() {
+            match get_command(line).map_err(|e| e.at_line(i))? {
+                Command::CD(name) => {
+                    if let Some(new_index) = file_system.get_node(working_node_index, &name) {
+                        working_node_index = new_index;
+                    } else {
+                        let error = ParseError::new(line, "a cd into a listed directory");
+                        return Err(error.at_line(i).into());
+                    }
+                }
+                Command::LS => {
+                    for (i, ls_line) in lines.clone().take_while(|(_, line)| !line.starts_with('$'))
+                    {
+                        let ls = LsResult::from_line(ls_line).map_err(|e| e.at_line(i))?;
+
+                        listed_size = listed_size.checked_add(ls.size).ok_or_else(|| {
+                            ParseError::new(ls_line, "file sizes totalling at most 2^64 - 1")
+                                .at_line(i)
+                        })?;
+
+                        let mut new_node = FileSystemNode::with_identifier(&ls.identifier);
+                        new_node.size = ls.size;
+
+                        file_system.add_child(working_node_index, new_node);
+
+                        lines.next(); // Skip line in the main iterator
+                    }
+                }
+            }
+        }
+
+        file_system.compute_total_size(0);
+
+        Ok(file_system)
+    }
+
+    fn part1(root: &Self::Input) -> Self::Answer1 {
+        root.nodes
+            .iter()
+            .filter(|&node| node.is_dir())
+            .filter(|&node| {
+                if let Some(total_size) = node.total_size {
+                    total_size <= 100000
+                } else {
+                    false
+                }
+            })
+            .map(|node| node.total_size.unwrap_or_default())
+            .sum()
+    }
+
+    fn part2(root: &Self::Input) -> Self::Answer2 {
+        static TOTAL_CAPACITY: u64 = 70000000;
+        static REQUIRED_SPACE: u64 = 30000000;
+        static MAX_TREE_SIZE: u64 = TOTAL_CAPACITY - REQUIRED_SPACE;
+
+        let current_size = root.nodes[0].total_size.unwrap_or_default();
+        // Must find directory as close to this as possible. Nothing has to go if the tree already fits
+        let required_deletion = current_size.saturating_sub(MAX_TREE_SIZE);
+        if required_deletion == 0 {
+            return 0;
+        }
+
+        root.nodes
+            .iter()
+            .filter(|&node| node.is_dir())
+            .filter(|&node| {
+                if let Some(total_size) = node.total_size {
+                    total_size >= required_deletion
+                } else {
+                    false
+                }
+            })
+            .map(|node| node.total_size.unwrap_or_default())
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+impl Generate for Day07 {
+    // `size` files and directories in a random, often deep, tree, explored with cd and ls
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        struct Entry {
+            name: String,
+            size: Option<u64>, // None for directories
+            children: Vec<usize>,
+        }
+
+        let mut entries = vec![Entry {
+            name: String::from("/"),
+            size: None,
+            children: Vec::new(),
+        }];
+        let mut dirs = vec![0];
+
+        // Keep the total within the disk's capacity
+        let max_file_size = (60_000_000 / size.max(1) as u64).clamp(1, 300_000);
+        let mut total_size = 0;
+
+        for i in 0..size {
+            // Favouring the newest directory builds deep trees
+            let parent = if rng.chance(1, 2) {
+                dirs[dirs.len() - 1]
+            } else {
+                *rng.pick(&dirs)
+            };
+
+            let entry = if rng.chance(1, 3) {
+                dirs.push(entries.len());
+                Entry {
+                    name: format!("d{}", i),
+                    size: None,
+                    children: Vec::new(),
+                }
+            } else {
+                let file_size = rng.between(1, max_file_size);
+                total_size += file_size;
+                Entry {
+                    name: format!("f{}.txt", i),
+                    size: Some(file_size),
+                    children: Vec::new(),
+                }
+            };
+
+            let index = entries.len();
+            entries[parent].children.push(index);
+            entries.push(entry);
+        }
+
+        // Part 2 needs more than 40000000 used so that something has to be deleted
+        if total_size <= 40_000_000 {
+            let index = entries.len();
+            entries[0].children.push(index);
+            entries.push(Entry {
+                name: String::from("padding.dat"),
+                size: Some(40_000_000 - total_size + rng.between(1, 1_000_000)),
+                children: Vec::new(),
+            });
+        }
+
+        enum Step {
+            Enter(usize),
+            Up,
+        }
+
+        let mut output = String::new();
+        let mut steps = vec![Step::Enter(0)];
+
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Up => output.push_str("$ cd ..\n"),
+                Step::Enter(index) => {
+                    let dir = &entries[index];
+                    output.push_str(&format!("$ cd {}\n$ ls\n", dir.name));
+
+                    for &child in &dir.children {
+                        let child = &entries[child];
+                        match child.size {
+                            Some(size) => output.push_str(&format!("{} {}\n", size, child.name)),
+                            None => output.push_str(&format!("dir {}\n", child.name)),
+                        }
+                    }
+
+                    // Visit subdirectories in listing order, coming back up after each
+                    for &child in dir.children.iter().rev() {
+                        if entries[child].size.is_none() {
+                            steps.push(Step::Up);
+                            steps.push(Step::Enter(child));
+                        }
+                    }
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Reference;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn computes_directory_sizes() {
+        let file_system = Day07::parse(SAMPLE).unwrap();
+
+        let a = file_system.get_node(0, "a").unwrap();
+        let e = file_system.get_node(a, "e").unwrap();
+
+        assert_eq!(file_system.nodes[0].total_size, Some(48381165));
+        assert_eq!(file_system.nodes[a].total_size, Some(94853));
+        assert_eq!(file_system.nodes[e].total_size, Some(584));
+        assert_eq!(file_system.get_node(e, ".."), Some(a));
+    }
+
+    #[test]
+    fn solves_sample() {
+        let file_system = Day07::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day07::part1(&file_system), 95437);
+        assert_eq!(Day07::part2(&file_system), 24933642);
+    }
+
+    #[test]
+    fn rejects_unknown_commands() {
+        assert!(get_command("$ rm -rf").is_err());
+        assert!(LsResult::from_line("12x b.txt").is_err());
+        assert!(Day07::parse("$ cd /\n$ cd missing\n").is_err());
+    }
+
+    #[test]
+    fn rejects_sizes_that_overflow() {
+        let input = "$ cd /\n$ ls\n18446744073709551615 a\n1 b\n";
+
+        match Day07::parse(input) {
+            Err(Error::Parse(e)) => assert_eq!(e.line, 4),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn solves_trees_that_already_fit() {
+        let file_system = Day07::parse("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n10 b\n").unwrap();
+
+        assert_eq!(Day07::part2(&file_system), 0);
+        assert_eq!(Day07::reference_part2(&file_system), 0);
+    }
+
+    #[test]
+    fn handles_deeply_nested_directories() {
+        let depth = 100_000;
+        let input = format!(
+            "$ cd /\n{}$ ls\n1 f\n",
+            "$ ls\ndir d\n$ cd d\n".repeat(depth)
+        );
+
+        let file_system = Day07::parse(&input).unwrap();
+
+        // Every directory, the root included, holds just the one file
+        assert_eq!(Day07::part1(&file_system), depth as u64 + 1);
+        assert_eq!(Day07::part2(&file_system), 0);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Day07, FileSystem};
+use aoc_core::Reference;
+
+// Size of a node and everything below it, summed without the cached totals. Walks the subtree with
+// an explicit stack, since a recursive walk would overflow on deeply nested directories.
+fn size_of(file_system: &FileSystem, index: usize) -> u64 {
+    let mut size = 0;
+    let mut stack = vec![index];
+
+    while let Some(index) = stack.pop() {
+        let node = &file_system.nodes[index];
+
+        size += node.size;
+        stack.extend(&node.children);
+    }
+
+    size
+}
+
+fn dir_sizes(file_system: &FileSystem) -> Vec<u64> {
+    (0..file_system.nodes.len())
+        .filter(|&index| file_system.nodes[index].is_dir())
+        .map(|index| size_of(file_system, index))
+        .collect()
+}
+
+impl Reference for Day07 {
+    fn reference_part1(file_system: &Self::Input) -> Self::Answer1 {
+        dir_sizes(file_system)
+            .into_iter()
+            .filter(|&size| size <= 100000)
+            .sum()
+    }
+
+    fn reference_part2(file_system: &Self::Input) -> Self::Answer2 {
+        let free = 70000000u64.saturating_sub(size_of(file_system, 0));
+        let required_deletion = 30000000u64.saturating_sub(free);
+        if required_deletion == 0 {
+            return 0;
+        }
+
+        dir_sizes(file_system)
+            .into_iter()
+            .filter(|&size| size >= required_deletion)
+            .min()
+            .unwrap_or(0)
+    }
+}